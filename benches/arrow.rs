@@ -0,0 +1,65 @@
+//! Compares the Arrow kernels to calling `IsThirteen::thirteen` on each element.
+use arrow_array::{Float64Array, Int32Array, StringArray};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use is_thirteen::arrow::{floats_thirteen, integers_thirteen, strings_thirteen};
+use is_thirteen::matcher::Matcher;
+use is_thirteen::IsThirteen;
+use std::hint::black_box;
+
+const LEN: usize = 100_000;
+
+fn integers(c: &mut Criterion) {
+    let array: Int32Array = (0..LEN as i32).map(|n| n % 20).collect();
+    let mut group = c.benchmark_group("integers");
+    group.bench_function(BenchmarkId::new("kernel", LEN), |b| {
+        b.iter(|| integers_thirteen(black_box(&array)))
+    });
+    group.bench_function(BenchmarkId::new("scalar", LEN), |b| {
+        b.iter(|| {
+            black_box(&array)
+                .iter()
+                .map(|n| n.map(|n| n.thirteen()))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn floats(c: &mut Criterion) {
+    let array: Float64Array = (0..LEN).map(|n| (n % 20) as f64).collect();
+    let mut group = c.benchmark_group("floats");
+    group.bench_function(BenchmarkId::new("kernel", LEN), |b| {
+        b.iter(|| floats_thirteen(black_box(&array)))
+    });
+    group.bench_function(BenchmarkId::new("scalar", LEN), |b| {
+        b.iter(|| {
+            black_box(&array)
+                .iter()
+                .map(|n| n.map(|n| n.thirteen()))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn strings(c: &mut Criterion) {
+    let words = ["13", "Thirteen", "fourteen", "XIII", "hello world", "十三"];
+    let array: StringArray = (0..LEN).map(|n| Some(words[n % words.len()])).collect();
+    let matcher = Matcher::new();
+    let mut group = c.benchmark_group("strings");
+    group.bench_function(BenchmarkId::new("kernel", LEN), |b| {
+        b.iter(|| strings_thirteen(black_box(&array), &matcher))
+    });
+    group.bench_function(BenchmarkId::new("scalar", LEN), |b| {
+        b.iter(|| {
+            black_box(&array)
+                .iter()
+                .map(|s| s.map(|s| s.thirteen()))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, integers, floats, strings);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+//! Compares the batch functions to calling `IsThirteen::thirteen` on each element.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use is_thirteen::batch::{count_thirteen, thirteen_mask};
+use is_thirteen::IsThirteen;
+use std::hint::black_box;
+
+const LEN: usize = 1_000_000;
+
+fn count(c: &mut Criterion) {
+    let integers: Vec<i32> = (0..LEN as i32).map(|n| n % 20).collect();
+    let bytes: Vec<u8> = (0..LEN).map(|n| n as u8).collect();
+    let floats: Vec<f32> = (0..LEN).map(|n| (n % 20) as f32).collect();
+
+    let mut group = c.benchmark_group("count");
+    group.bench_function(BenchmarkId::new("i32/batch", LEN), |b| {
+        b.iter(|| count_thirteen(black_box(&integers)))
+    });
+    group.bench_function(BenchmarkId::new("i32/scalar", LEN), |b| {
+        b.iter(|| black_box(&integers).iter().filter(|n| n.thirteen()).count())
+    });
+    group.bench_function(BenchmarkId::new("u8/batch", LEN), |b| {
+        b.iter(|| count_thirteen(black_box(&bytes)))
+    });
+    group.bench_function(BenchmarkId::new("u8/scalar", LEN), |b| {
+        b.iter(|| black_box(&bytes).iter().filter(|n| n.thirteen()).count())
+    });
+    group.bench_function(BenchmarkId::new("f32/batch", LEN), |b| {
+        b.iter(|| count_thirteen(black_box(&floats)))
+    });
+    group.bench_function(BenchmarkId::new("f32/scalar", LEN), |b| {
+        b.iter(|| black_box(&floats).iter().filter(|n| n.thirteen()).count())
+    });
+    group.finish();
+}
+
+fn mask(c: &mut Criterion) {
+    let integers: Vec<i32> = (0..LEN as i32).map(|n| n % 20).collect();
+
+    let mut group = c.benchmark_group("mask");
+    group.bench_function(BenchmarkId::new("i32/batch", LEN), |b| {
+        b.iter(|| thirteen_mask(black_box(&integers)))
+    });
+    group.bench_function(BenchmarkId::new("i32/scalar", LEN), |b| {
+        b.iter(|| {
+            black_box(&integers)
+                .iter()
+                .map(|n| n.thirteen())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, count, mask);
+criterion_main!(benches);
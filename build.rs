@@ -0,0 +1,244 @@
+//! Checks `data/thirteen_strings.toml` and generates the lookup tables that
+//! `src/thirteen_strings.rs` includes. With the `digests` feature, also hashes the seeds in
+//! `data/hash_seeds.toml` for `src/hashes.rs`.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const DATA: &str = "data/thirteen_strings.toml";
+
+/// The default seed list of `hashes::identify()`, which `IS_THIRTEEN_HASH_SEEDS` replaces.
+#[cfg(feature = "digests")]
+const HASH_SEEDS: &str = "data/hash_seeds.toml";
+
+/// The cargo feature and `Categories` flag of each category.
+const CATEGORIES: &[(&str, &str)] = &[
+    ("numerals", "NUMERALS"),
+    ("languages", "LANGUAGES"),
+    ("leetspeak", "LEETSPEAK"),
+    ("encodings", "ENCODINGS"),
+    ("hashes", "HASHES"),
+    ("pop-culture", "POP_CULTURE"),
+    ("politics", "POLITICS"),
+    ("urls", "URLS"),
+];
+
+struct Entry {
+    value: String,
+    category: &'static str,
+    language: Option<String>,
+    note: Option<String>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", DATA);
+
+    let text = fs::read_to_string(DATA).unwrap_or_else(|e| panic!("{}: {}", DATA, e));
+    let entries = match parse(&text) {
+        Ok(entries) => entries,
+        Err(errors) => panic!("{} is invalid:\n{}", DATA, errors.join("\n")),
+    };
+
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("thirteen_strings.rs"),
+        generate(&entries),
+    )
+    .unwrap();
+
+    #[cfg(feature = "digests")]
+    {
+        println!("cargo:rerun-if-env-changed=IS_THIRTEEN_HASH_SEEDS");
+        // A relative path is relative to the package root, where build scripts run
+        let path = env::var("IS_THIRTEEN_HASH_SEEDS").unwrap_or_else(|_| HASH_SEEDS.to_string());
+        println!("cargo:rerun-if-changed={}", path);
+        let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        let seeds = match parse_seeds(&text) {
+            Ok(seeds) => seeds,
+            Err(errors) => panic!("{} is invalid:\n{}", path, errors.join("\n")),
+        };
+        fs::write(
+            Path::new(&out_dir).join("hashes.rs"),
+            generate_hashes(&seeds),
+        )
+        .unwrap();
+    }
+}
+
+/// Parses and validates the data file. Returns every problem found, not just the first.
+fn parse(text: &str) -> Result<Vec<Entry>, Vec<String>> {
+    let table: toml::Table = text.parse().map_err(|e| vec![format!("{}", e)])?;
+    let items = match table.get("entries").and_then(|entries| entries.as_array()) {
+        Some(items) => items,
+        None => return Err(vec!["expected an `entries` array".to_string()]),
+    };
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    for (i, item) in items.iter().enumerate() {
+        let entry = match parse_entry(item) {
+            Ok(entry) => entry,
+            Err(e) => {
+                errors.push(format!("entries[{}]: {}", i, e));
+                continue;
+            }
+        };
+        let mut error = |message: &str| errors.push(format!("{:?}: {}", entry.value, message));
+        if !seen.insert(entry.value.clone()) {
+            error("duplicate entry");
+        }
+        if entry.value != entry.value.to_lowercase() {
+            error("not lowercase, so input never equals it after case folding");
+        }
+        if let Some(rule) = shadowing_rule(&entry.value) {
+            error(&format!("always matched by the {} rule first", rule));
+        }
+        entries.push(entry);
+    }
+
+    if errors.is_empty() {
+        Ok(entries)
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_entry(item: &toml::Value) -> Result<Entry, String> {
+    let table = item.as_table().ok_or("expected a table")?;
+    if let Some(key) = table
+        .keys()
+        .find(|key| !matches!(key.as_str(), "value" | "category" | "language" | "note"))
+    {
+        return Err(format!("unknown field `{}`", key));
+    }
+    let string = |key: &str| match table.get(key) {
+        Some(value) => match value.as_str() {
+            Some(s) => Ok(Some(s.to_string())),
+            None => Err(format!("`{}` must be a string", key)),
+        },
+        None => Ok(None),
+    };
+
+    let value = string("value")?.ok_or("missing `value`")?;
+    let category = string("category")?.ok_or("missing `category`")?;
+    let category = CATEGORIES
+        .iter()
+        .map(|&(feature, _)| feature)
+        .find(|&feature| feature == category)
+        .ok_or_else(|| format!("unknown category {:?}", category))?;
+    Ok(Entry {
+        value,
+        category,
+        language: string("language")?,
+        note: string("note")?,
+    })
+}
+
+/// Returns the rule of `explain()` that matches `value` before the dataset is consulted, if any.
+/// Mirrors the checks in `explain_with()`.
+fn shadowing_rule(value: &str) -> Option<&'static str> {
+    let first = value.chars().next()?;
+    if value == "13" {
+        Some("literal")
+    } else if value.len() == 13 && value.bytes().all(|b| matches!(b, b'I' | b'l' | b'1')) {
+        Some("strokes")
+    } else if value.chars().count() == 13 && value.chars().all(|c| c == first) {
+        Some("repeated character")
+    } else {
+        None
+    }
+}
+
+fn generate(entries: &[Entry]) -> String {
+    let mut code = String::new();
+    for &(feature, flag) in CATEGORIES {
+        let mut map = phf_codegen::Map::new();
+        for entry in entries.iter().filter(|entry| entry.category == feature) {
+            map.entry(
+                entry.value.as_str(),
+                &format!(
+                    "Metadata {{ category: Categories::{}, language: {:?}, note: {:?} }}",
+                    flag, entry.language, entry.note
+                ),
+            );
+        }
+        writeln!(
+            code,
+            "#[cfg(feature = {:?})]\nstatic {}: Map<&str, Metadata> = {};\n",
+            feature,
+            flag,
+            map.build()
+        )
+        .unwrap();
+    }
+    code
+}
+
+/// Parses and validates a seed list. Returns every problem found, not just the first.
+#[cfg(feature = "digests")]
+fn parse_seeds(text: &str) -> Result<Vec<String>, Vec<String>> {
+    let table: toml::Table = text.parse().map_err(|e| vec![format!("{}", e)])?;
+    let items = match table.get("seeds").and_then(|seeds| seeds.as_array()) {
+        Some(items) => items,
+        None => return Err(vec!["expected a `seeds` array".to_string()]),
+    };
+
+    let mut seeds = Vec::new();
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    for (i, item) in items.iter().enumerate() {
+        match item.as_str() {
+            Some(seed) if !seen.insert(seed) => errors.push(format!("{:?}: duplicate seed", seed)),
+            Some(seed) => seeds.push(seed.to_string()),
+            None => errors.push(format!("seeds[{}]: expected a string", i)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(seeds)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns the digest of `seed` with each algorithm, named by its `hashes::Algorithm` variant.
+/// Integer digests are big-endian, as their hex forms are usually written.
+#[cfg(feature = "digests")]
+fn digests(seed: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+    use sha2::Digest;
+    use xxhash_rust::{xxh3, xxh32, xxh64};
+    vec![
+        ("Md5", md5::Md5::digest(seed).to_vec()),
+        ("Sha1", sha1::Sha1::digest(seed).to_vec()),
+        ("Sha256", sha2::Sha256::digest(seed).to_vec()),
+        ("Sha512", sha2::Sha512::digest(seed).to_vec()),
+        ("Crc32", crc32fast::hash(seed).to_be_bytes().to_vec()),
+        ("Xxh32", xxh32::xxh32(seed, 0).to_be_bytes().to_vec()),
+        ("Xxh64", xxh64::xxh64(seed, 0).to_be_bytes().to_vec()),
+        ("Xxh3", xxh3::xxh3_64(seed).to_be_bytes().to_vec()),
+    ]
+}
+
+/// Generates `DIGESTS`, which maps lowercase hex digests to their algorithm and seed. If two
+/// digests are equal, the first seed and algorithm are kept.
+#[cfg(feature = "digests")]
+fn generate_hashes(seeds: &[String]) -> String {
+    let mut map = phf_codegen::Map::new();
+    let mut seen = HashSet::new();
+    for seed in seeds {
+        for (algorithm, digest) in digests(seed.as_bytes()) {
+            let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+            if seen.insert(hex.clone()) {
+                map.entry(hex, &format!("(Algorithm::{}, {:?})", algorithm, seed));
+            }
+        }
+    }
+    format!(
+        "static DIGESTS: Map<&str, (Algorithm, &str)> = {};\n",
+        map.build()
+    )
+}
@@ -0,0 +1,84 @@
+#ifndef IS_THIRTEEN_H
+#define IS_THIRTEEN_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns `true` if the string is thirteen.
+//
+// # Safety
+// `s` must be null or point to `len` readable bytes.
+bool is_thirteen_str(const char *s, size_t len);
+
+// Returns `true` if `n == 13`.
+bool is_thirteen_i64(int64_t n);
+
+// Returns `true` if `n` is approximately 13.
+bool is_thirteen_f64(double n);
+
+// Returns `true` if `c` is a thirteen character. Values that are not Unicode scalar values are
+// not thirteen.
+bool is_thirteen_char(uint32_t c);
+
+// Returns `true` if `n` is in [12.5, 13.5). See [`Roughly`].
+bool is_thirteen_roughly(double n);
+
+// Returns `true` if `n` is at most `radius` away from 13. See [`Within`].
+bool is_thirteen_within(double n, double radius);
+
+// Returns `true` if 13 divides `n`. See [`DivisibleBy`].
+bool is_thirteen_divisible_by_i64(int64_t n);
+
+// Returns `true` if 13 divides `n`. See [`DivisibleBy`].
+bool is_thirteen_divisible_by_f64(double n);
+
+// Returns `true` if `n > 13`. See [`GreaterThan`].
+bool is_thirteen_greater_than_i64(int64_t n);
+
+// Returns `true` if `n > 13`. See [`GreaterThan`].
+bool is_thirteen_greater_than_f64(double n);
+
+// Returns `true` if `n < 13`. See [`LessThan`].
+bool is_thirteen_less_than_i64(int64_t n);
+
+// Returns `true` if `n < 13`. See [`LessThan`].
+bool is_thirteen_less_than_f64(double n);
+
+// Returns `true` if the string contains the letters of "thirteen". See [`CanSpell`].
+//
+// # Safety
+// `s` must be null or point to `len` readable bytes.
+bool is_thirteen_can_spell(const char *s, size_t len);
+
+// Returns `true` if the string is an anagram of "thirteen". See [`AnagramOf`].
+//
+// # Safety
+// `s` must be null or point to `len` readable bytes.
+bool is_thirteen_anagram_of(const char *s, size_t len);
+
+// Returns `true` if the string is "thirteen" spelled backwards. See [`Backwards`].
+//
+// # Safety
+// `s` must be null or point to `len` readable bytes.
+bool is_thirteen_backwards(const char *s, size_t len);
+
+// Returns `true` if the string is "aluminum". See [`AtomicNumber`].
+//
+// # Safety
+// `s` must be null or point to `len` readable bytes.
+bool is_thirteen_atomic_number(const char *s, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* IS_THIRTEEN_H */
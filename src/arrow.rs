@@ -0,0 +1,99 @@
+//! Kernels that compare whole Apache Arrow arrays to thirteen. Every kernel returns a
+//! [`BooleanArray`] with the same nulls as its input.
+//!
+//! ```
+//! use arrow_array::{Array, Int32Array, StringArray};
+//! use is_thirteen::arrow::thirteen;
+//!
+//! let mask = thirteen(&Int32Array::from(vec![Some(13), None, Some(14)])).unwrap();
+//! assert_eq!(mask.value(0), true);
+//! assert!(mask.is_null(1));
+//!
+//! let mask = thirteen(&StringArray::from(vec!["XIII", "fourteen"])).unwrap();
+//! assert_eq!(mask.values().iter().collect::<Vec<_>>(), [true, false]);
+//! ```
+
+use crate::matcher::Matcher;
+use crate::IsThirteen;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{
+    Array, ArrowPrimitiveType, BooleanArray, GenericBinaryArray, GenericStringArray,
+    OffsetSizeTrait, PrimitiveArray,
+};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer};
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, DataType};
+
+/// Compares every element of `array` to thirteen. Supports integer, float, string and binary
+/// arrays.
+pub fn thirteen(array: &dyn Array) -> Result<BooleanArray, ArrowError> {
+    thirteen_with(array, &Matcher::new())
+}
+
+/// [`thirteen`], checking strings with `matcher`.
+pub fn thirteen_with(array: &dyn Array, matcher: &Matcher) -> Result<BooleanArray, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Int8 => integers_thirteen(array.as_primitive::<Int8Type>()),
+        DataType::Int16 => integers_thirteen(array.as_primitive::<Int16Type>()),
+        DataType::Int32 => integers_thirteen(array.as_primitive::<Int32Type>()),
+        DataType::Int64 => integers_thirteen(array.as_primitive::<Int64Type>()),
+        DataType::UInt8 => integers_thirteen(array.as_primitive::<UInt8Type>()),
+        DataType::UInt16 => integers_thirteen(array.as_primitive::<UInt16Type>()),
+        DataType::UInt32 => integers_thirteen(array.as_primitive::<UInt32Type>()),
+        DataType::UInt64 => integers_thirteen(array.as_primitive::<UInt64Type>()),
+        DataType::Float32 => floats_thirteen(array.as_primitive::<Float32Type>()),
+        DataType::Float64 => floats_thirteen(array.as_primitive::<Float64Type>()),
+        DataType::Utf8 => strings_thirteen(array.as_string::<i32>(), matcher),
+        DataType::LargeUtf8 => strings_thirteen(array.as_string::<i64>(), matcher),
+        DataType::Binary => binaries_thirteen(array.as_binary::<i32>(), matcher),
+        DataType::LargeBinary => binaries_thirteen(array.as_binary::<i64>(), matcher),
+        data_type => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot compare {} to thirteen",
+                data_type
+            )))
+        }
+    })
+}
+
+/// Compares an integer array to 13 with Arrow's vectorized comparison kernel.
+pub fn integers_thirteen<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> BooleanArray {
+    let thirteen = PrimitiveArray::<T>::new_scalar(T::Native::usize_as(13));
+    cmp::eq(array, &thirteen).expect("array and scalar have the same type")
+}
+
+/// Compares a float array to thirteen like `f32::thirteen` and `f64::thirteen` do.
+pub fn floats_thirteen<T>(array: &PrimitiveArray<T>) -> BooleanArray
+where
+    T: ArrowPrimitiveType,
+    T::Native: IsThirteen,
+{
+    BooleanArray::from_unary(array, |n| n.thirteen())
+}
+
+/// Checks a string array with `matcher`.
+pub fn strings_thirteen<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    matcher: &Matcher,
+) -> BooleanArray {
+    // Null slots are checked too, which is cheaper than skipping them. Their results are hidden by
+    // the null buffer.
+    let mask = matcher.mask((0..array.len()).map(|i| array.value(i)));
+    BooleanArray::new(BooleanBuffer::from(mask), array.nulls().cloned())
+}
+
+/// Checks a binary array with `matcher`. Values that are not UTF-8 are not thirteen.
+pub fn binaries_thirteen<O: OffsetSizeTrait>(
+    array: &GenericBinaryArray<O>,
+    matcher: &Matcher,
+) -> BooleanArray {
+    // "" is never thirteen, so it stands in for invalid UTF-8
+    let strings = (0..array.len()).map(|i| std::str::from_utf8(array.value(i)).unwrap_or(""));
+    let mask = matcher.mask(strings);
+    BooleanArray::new(BooleanBuffer::from(mask), array.nulls().cloned())
+}
+
+#[cfg(test)]
+#[path = "arrow_test.rs"]
+mod arrow_test;
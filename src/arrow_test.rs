@@ -0,0 +1,97 @@
+use super::*;
+use crate::registry::ThirteenRegistry;
+use arrow_array::{
+    BinaryArray, Float32Array, Float64Array, Int16Array, Int64Array, LargeStringArray, StringArray,
+    UInt8Array,
+};
+use std::sync::Arc;
+
+fn values(mask: &BooleanArray) -> Vec<Option<bool>> {
+    mask.iter().collect()
+}
+
+#[test]
+fn integers() {
+    let array = Int64Array::from(vec![Some(13), None, Some(-13), Some(14)]);
+    assert_eq!(
+        values(&thirteen(&array).unwrap()),
+        [Some(true), None, Some(false), Some(false)]
+    );
+    let array = UInt8Array::from(vec![13, 0, 255]);
+    assert_eq!(
+        values(&integers_thirteen(&array)),
+        [Some(true), Some(false), Some(false)]
+    );
+}
+
+#[test]
+fn floats() {
+    let array = Float64Array::from(vec![Some(13.0), Some(13.0 + 1e-12), None, Some(f64::NAN)]);
+    let expected: Vec<Option<bool>> = array.iter().map(|n| n.map(|n| n.thirteen())).collect();
+    assert_eq!(values(&thirteen(&array).unwrap()), expected);
+    assert_eq!(expected[0], Some(true));
+
+    let array = Float32Array::from(vec![13.0, 12.9]);
+    assert_eq!(
+        values(&thirteen(&array).unwrap()),
+        [Some(true), Some(false)]
+    );
+}
+
+#[test]
+fn strings() {
+    let inputs = vec![
+        Some("13"),
+        Some("Thirteen"),
+        None,
+        Some("fourteen"),
+        Some("十三"),
+    ];
+    let expected = [Some(true), Some(true), None, Some(false), Some(true)];
+    assert_eq!(
+        values(&thirteen(&StringArray::from(inputs.clone())).unwrap()),
+        expected
+    );
+    assert_eq!(
+        values(&thirteen(&LargeStringArray::from(inputs)).unwrap()),
+        expected
+    );
+}
+
+#[test]
+fn binaries() {
+    let array = BinaryArray::from(vec![Some(b"xiii".as_ref()), None, Some(b"\xff".as_ref())]);
+    assert_eq!(
+        values(&thirteen(&array).unwrap()),
+        [Some(true), None, Some(false)]
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = StringArray::from(vec!["13", "14", "xiii"]).slice(1, 2);
+    assert_eq!(
+        values(&thirteen(&array).unwrap()),
+        [Some(false), Some(true)]
+    );
+}
+
+#[test]
+fn unsupported() {
+    let array: Arc<dyn Array> = Arc::new(BooleanArray::from(vec![true]));
+    assert!(thirteen(&array).is_err());
+    assert!(thirteen(&Int16Array::from(vec![13])).is_ok());
+}
+
+#[test]
+fn registry() {
+    let mut registry = ThirteenRegistry::new();
+    registry.insert("Floor 13");
+    registry.remove("xiii");
+    let matcher = Matcher::from(registry);
+    let array = StringArray::from(vec!["floor 13", "XIII", "13"]);
+    assert_eq!(
+        values(&thirteen_with(&array, &matcher).unwrap()),
+        [Some(true), Some(false), Some(true)]
+    );
+}
@@ -0,0 +1,140 @@
+//! Compares large numeric slices to thirteen. The loops work on fixed-size chunks so that the
+//! compiler can vectorize them, and give the same verdicts as the scalar [`IsThirteen`] impls.
+//!
+//! ```
+//! use is_thirteen::batch::{count_thirteen, thirteen_mask};
+//!
+//! let values = [12, 13, 14, 13];
+//! assert_eq!(count_thirteen(&values), 2);
+//! assert_eq!(thirteen_mask(&values).iter_ones().collect::<Vec<_>>(), [1, 3]);
+//! ```
+//!
+//! [`IsThirteen`]: crate::IsThirteen
+
+use bitvec::prelude::*;
+use std::convert::TryInto;
+
+/// Elements per chunk. One chunk fills one word of the mask.
+const CHUNK: usize = u64::BITS as usize;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive number that the batch functions accept.
+pub trait Lane: Copy + private::Sealed {
+    /// Same as `IsThirteen::thirteen`, but written to inline into vectorized loops.
+    #[doc(hidden)]
+    fn lane_thirteen(self) -> bool;
+}
+
+macro_rules! impl_lane_for_integer {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl Lane for $type {
+                #[inline(always)]
+                fn lane_thirteen(self) -> bool {
+                    self == 13
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_for_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_lane_for_float {
+    ($($type:ty),*) => {
+        $(
+            impl private::Sealed for $type {}
+
+            impl Lane for $type {
+                #[inline(always)]
+                fn lane_thirteen(self) -> bool {
+                    (self - 13.0).abs() < <$type>::EPSILON
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_for_float!(f32, f64);
+
+/// Returns a mask with a bit set for every element of `values` that is thirteen.
+pub fn thirteen_mask<T: Lane>(values: &[T]) -> BitVec<usize> {
+    let chunks = values.chunks_exact(CHUNK);
+    let remainder = chunks.remainder();
+    let mut words: Vec<u64> = chunks.map(|chunk| chunk_word(to_array(chunk))).collect();
+    if !remainder.is_empty() {
+        words.push(remainder_word(remainder));
+    }
+    // bitvec only stores u64 words on 64-bit targets, so each chunk word is split into as many
+    // usize words as it takes, lowest bits first
+    let words_per_chunk = (u64::BITS / usize::BITS) as usize;
+    let words = words
+        .into_iter()
+        .flat_map(|word| {
+            (0..words_per_chunk).map(move |i| (word >> (i as u32 * usize::BITS)) as usize)
+        })
+        .collect();
+    let mut mask = BitVec::from_vec(words);
+    mask.truncate(values.len());
+    mask
+}
+
+/// Returns the number of elements of `values` that are thirteen.
+pub fn count_thirteen<T: Lane>(values: &[T]) -> usize {
+    let chunks = values.chunks_exact(CHUNK);
+    let remainder = chunks.remainder();
+    chunks
+        .map(|chunk| chunk_count(to_array(chunk)))
+        .sum::<usize>()
+        + remainder_word(remainder).count_ones() as usize
+}
+
+fn to_array<T>(chunk: &[T]) -> &[T; CHUNK] {
+    chunk.try_into().expect("chunks_exact() yields full chunks")
+}
+
+// The functions below take fixed-size arrays because loops with a constant trip count are the
+// ones that LLVM unrolls and vectorizes.
+
+/// Packs the verdicts for a chunk into a word, first element in the lowest bit.
+#[inline(always)]
+fn chunk_word<T: Lane>(chunk: &[T; CHUNK]) -> u64 {
+    let mut verdicts = [0u8; CHUNK];
+    for (verdict, &n) in verdicts.iter_mut().zip(chunk) {
+        *verdict = n.lane_thirteen() as u8;
+    }
+    // Gathers the lowest bit of each of 8 bytes into the top byte
+    const GATHER: u64 = 0x0102_0408_1020_4080;
+    let mut word = 0;
+    for (i, bytes) in verdicts.chunks_exact(8).enumerate() {
+        let bytes = u64::from_le_bytes(bytes.try_into().unwrap());
+        word |= (bytes.wrapping_mul(GATHER) >> 56) << (i * 8);
+    }
+    word
+}
+
+#[inline(always)]
+fn chunk_count<T: Lane>(chunk: &[T; CHUNK]) -> usize {
+    let mut count = 0u8;
+    for &n in chunk {
+        count += n.lane_thirteen() as u8;
+    }
+    count as usize
+}
+
+/// [`chunk_word`] for the last, partial chunk.
+fn remainder_word<T: Lane>(remainder: &[T]) -> u64 {
+    remainder
+        .iter()
+        .enumerate()
+        .fold(0, |word, (i, &n)| word | (n.lane_thirteen() as u64) << i)
+}
+
+#[cfg(test)]
+#[path = "batch_test.rs"]
+mod batch_test;
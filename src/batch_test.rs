@@ -0,0 +1,57 @@
+use super::*;
+use crate::IsThirteen;
+use rstest::rstest;
+use std::fmt::Debug;
+
+/// Checks the batch functions against the scalar `IsThirteen` impl.
+fn check<T: Lane + IsThirteen + Debug>(values: &[T]) {
+    let mask = thirteen_mask(values);
+    assert_eq!(mask.len(), values.len());
+    for (i, n) in values.iter().enumerate() {
+        assert_eq!(mask[i], n.thirteen(), "{:?} at {}", n, i);
+    }
+    assert_eq!(
+        count_thirteen(values),
+        values.iter().filter(|n| n.thirteen()).count()
+    );
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(63)]
+#[case(64)]
+#[case(65)]
+#[case(1000)]
+fn lengths(#[case] len: usize) {
+    let values: Vec<i32> = (0..len as i32).map(|n| n % 17).collect();
+    check(&values);
+    let values: Vec<u8> = (0..len).map(|n| n as u8).collect();
+    check(&values);
+    let values: Vec<f32> = (0..len).map(|n| (n % 15) as f32).collect();
+    check(&values);
+}
+
+#[test]
+fn integers() {
+    check(&[13i8, -13, 0, i8::MAX, i8::MIN]);
+    check(&[13u64, 0, u64::MAX]);
+    check(&[13i128, -13, i128::MAX]);
+    check(&[13usize, 14]);
+}
+
+#[test]
+fn floats() {
+    for &n in &[
+        13.0f64,
+        13.0 + 1e-15,
+        13.0 - 1e-15,
+        12.0,
+        f64::NAN,
+        f64::INFINITY,
+    ] {
+        check(&[n; 70]);
+    }
+    let next_up = f32::from_bits(13.0f32.to_bits() + 1);
+    check(&[13.0f32, next_up, f32::NAN, -13.0]);
+}
@@ -0,0 +1,204 @@
+//! Classical ciphers. [`crack`] tries to decrypt a string with every Caesar shift, ROT47, Atbash
+//! and Vigenère ciphers with short keys, and reports the first decryption that is thirteen.
+//!
+//! ```
+//! # #[cfg(feature = "languages")] {
+//! use is_thirteen::cipher::{crack, Cipher, Encrypted};
+//! use is_thirteen::IsThirteen;
+//!
+//! let decryption = crack("guvegrra").unwrap();
+//! assert_eq!(decryption.cipher, Cipher::Caesar(13));
+//! assert_eq!(decryption.plaintext, "thirteen");
+//!
+//! let ciphertext = Cipher::Vigenere("ky".to_string()).encrypt("thirteen");
+//! assert_eq!(crack(&ciphertext).unwrap().cipher, Cipher::Vigenere("ky".to_string()));
+//! assert!(Encrypted("GSRIGVVM").thirteen());
+//! # }
+//! ```
+
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use crate::{explain, IsThirteen, Reason};
+use std::fmt;
+
+/// The longest Vigenère key that [`crack`] finds.
+const MAX_KEY_LEN: usize = 4;
+
+/// How many times a Vigenère key must fit in the letters of the ciphertext. With fewer, too many
+/// words decrypt to an entry with some key, like `"these"` to `"trece"` with the key `"aq"`.
+const MIN_KEY_REPEATS: usize = 3;
+
+/// A classical cipher and its key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cipher {
+    /// Shifts ASCII letters forward by this many places. A shift of 13 is ROT13, which is its own
+    /// inverse.
+    Caesar(u8),
+    /// Rotates printable ASCII characters by 47 places, which is its own inverse.
+    Rot47,
+    /// Replaces ASCII letters with the letter at the same place from the end of the alphabet.
+    Atbash,
+    /// Shifts each ASCII letter by the next letter of the lowercase key, where `a` is 0. Other
+    /// characters are kept and do not use a key letter.
+    Vigenere(String),
+}
+
+impl Cipher {
+    /// Encrypts `plaintext`.
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        self.apply(plaintext, false)
+    }
+
+    /// Decrypts `ciphertext`.
+    pub fn decrypt(&self, ciphertext: &str) -> String {
+        self.apply(ciphertext, true)
+    }
+
+    fn apply(&self, s: &str, decrypt: bool) -> String {
+        let shift_by = |c: char, shift: u8| {
+            let shift = if decrypt { 26 - shift % 26 } else { shift % 26 };
+            match c {
+                'a'..='z' => ((c as u8 - b'a' + shift) % 26 + b'a') as char,
+                'A'..='Z' => ((c as u8 - b'A' + shift) % 26 + b'A') as char,
+                c => c,
+            }
+        };
+        match self {
+            Cipher::Caesar(shift) => s.chars().map(|c| shift_by(c, *shift)).collect(),
+            Cipher::Rot47 => s
+                .chars()
+                .map(|c| match c {
+                    '!'..='~' => ((c as u8 - b'!' + 47) % 94 + b'!') as char,
+                    c => c,
+                })
+                .collect(),
+            Cipher::Atbash => s
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' => (b'z' - (c as u8 - b'a')) as char,
+                    'A'..='Z' => (b'Z' - (c as u8 - b'A')) as char,
+                    c => c,
+                })
+                .collect(),
+            Cipher::Vigenere(key) => {
+                let shifts: Vec<u8> = key
+                    .bytes()
+                    .filter(u8::is_ascii_alphabetic)
+                    .map(|b| b.to_ascii_lowercase() - b'a')
+                    .collect();
+                if shifts.is_empty() {
+                    return s.to_string();
+                }
+                let mut shifts = shifts.iter().cycle();
+                s.chars()
+                    .map(|c| {
+                        if c.is_ascii_alphabetic() {
+                            shift_by(c, *shifts.next().unwrap())
+                        } else {
+                            c
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Cipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cipher::Caesar(13) => f.write_str("ROT13"),
+            Cipher::Caesar(shift) => write!(f, "Caesar shift {}", shift),
+            Cipher::Rot47 => f.write_str("ROT47"),
+            Cipher::Atbash => f.write_str("Atbash"),
+            Cipher::Vigenere(key) => write!(f, "Vigenère with key {:?}", key),
+        }
+    }
+}
+
+/// A decryption that is thirteen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decryption {
+    pub cipher: Cipher,
+    pub plaintext: String,
+    /// Why `plaintext` is thirteen.
+    pub reason: Reason,
+}
+
+/// `Encrypted` is thirteen if [`crack`] finds a decryption that is thirteen.
+#[derive(Debug, Clone, Copy)]
+pub struct Encrypted<'s>(pub &'s str);
+
+impl IsThirteen for Encrypted<'_> {
+    fn thirteen(&self) -> bool {
+        crack(self.0).is_some()
+    }
+}
+
+/// Returns the first decryption of `ciphertext` that is thirteen. The ciphers are tried in this
+/// order: ROT13, the other Caesar shifts from 1 to 25, ROT47, Atbash, and Vigenère with keys of 2
+/// to 4 letters, shortest first. A Vigenère key must fit at least 3 times in the letters of
+/// `ciphertext`. Decryptions that do not change `ciphertext`, like ROT13 of `"13"`, are skipped.
+pub fn crack(ciphertext: &str) -> Option<Decryption> {
+    let fixed = std::iter::once(Cipher::Caesar(13))
+        .chain((1..26).filter(|&shift| shift != 13).map(Cipher::Caesar))
+        .chain([Cipher::Rot47, Cipher::Atbash]);
+    fixed
+        .chain(vigenere_keys(ciphertext).into_iter().map(Cipher::Vigenere))
+        .find_map(|cipher| {
+            let plaintext = cipher.decrypt(ciphertext);
+            if plaintext == ciphertext {
+                return None;
+            }
+            let reason = explain(&plaintext)?;
+            Some(Decryption {
+                cipher,
+                plaintext,
+                reason,
+            })
+        })
+}
+
+/// Returns the Vigenère keys that decrypt `ciphertext` to an entry, found by comparing it to
+/// every entry with the same non-letters. Keys that are one repeated letter are Caesar shifts
+/// and are left out. Shorter keys come first, then keys for entries in earlier categories, since
+/// a key shifted by 3 also decrypts to the Caesar entry `"wkluwhhq"`.
+fn vigenere_keys(ciphertext: &str) -> Vec<String> {
+    let ciphertext = ciphertext.to_ascii_lowercase();
+    let letters = ciphertext.bytes().filter(u8::is_ascii_alphabetic).count();
+    let mut keys = Vec::new();
+    for entry in THIRTEEN_STRINGS.iter() {
+        if entry.len() != ciphertext.len() {
+            continue;
+        }
+        let mut shifts = Vec::with_capacity(letters);
+        let same_shape = entry.bytes().zip(ciphertext.bytes()).all(|(p, c)| {
+            if p.is_ascii_lowercase() && c.is_ascii_lowercase() {
+                shifts.push((c + 26 - p) % 26);
+                true
+            } else {
+                p == c
+            }
+        });
+        if !same_shape {
+            continue;
+        }
+        let period = (2..=MAX_KEY_LEN)
+            .filter(|&len| len * MIN_KEY_REPEATS <= shifts.len())
+            .find(|&len| (len..shifts.len()).all(|i| shifts[i] == shifts[i - len]));
+        if let Some(len) = period {
+            let key: String = shifts[..len].iter().map(|s| (s + b'a') as char).collect();
+            if key.bytes().any(|b| b != key.as_bytes()[0]) {
+                let rank = THIRTEEN_STRINGS
+                    .category(entry)
+                    .map_or(0, |category| category.bits());
+                keys.push((len, rank, key));
+            }
+        }
+    }
+    keys.sort_unstable();
+    keys.into_iter().map(|(_, _, key)| key).collect()
+}
+
+#[cfg(test)]
+#[path = "cipher_test.rs"]
+mod cipher_test;
@@ -0,0 +1,92 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case(Cipher::Caesar(3), "thirteen", "wkluwhhq")]
+#[case(Cipher::Caesar(13), "Thirteen!", "Guvegrra!")]
+#[case(Cipher::Caesar(25), "abz", "zay")]
+#[case(Cipher::Rot47, "13", "`b")]
+#[case(Cipher::Atbash, "Thirteen", "Gsrigvvm")]
+#[case(Cipher::Vigenere("key".to_string()), "thirteen", "dlgbxcor")]
+#[case(Cipher::Vigenere("KEY".to_string()), "thir teen", "dlgb xcor")]
+#[case(Cipher::Vigenere(String::new()), "thirteen", "thirteen")]
+fn round_trip(#[case] cipher: Cipher, #[case] plaintext: &str, #[case] ciphertext: &str) {
+    assert_eq!(cipher.encrypt(plaintext), ciphertext);
+    assert_eq!(cipher.decrypt(ciphertext), plaintext);
+}
+
+#[rstest]
+#[cfg_attr(
+    feature = "languages",
+    case("guvegrra", Cipher::Caesar(13), "thirteen")
+)]
+#[cfg_attr(
+    feature = "languages",
+    case("GUVEGRRA", Cipher::Caesar(13), "THIRTEEN")
+)]
+#[cfg_attr(feature = "languages", case("wkluwhhq", Cipher::Caesar(3), "thirteen"))]
+#[case("`b", Cipher::Rot47, "13")]
+#[cfg_attr(feature = "languages", case("gsrigvvm", Cipher::Atbash, "thirteen"))]
+// "wkluwhhq" is an entry, so "thirteen" is a Caesar cipher of it
+#[cfg_attr(
+    feature = "encodings",
+    case("thirteen", Cipher::Caesar(23), "wkluwhhq")
+)]
+#[cfg_attr(
+    feature = "languages",
+    case("dfspdcol", Cipher::Vigenere("ky".to_string()), "thirteen")
+)]
+#[cfg_attr(
+    feature = "languages",
+    case("Dfspdcol", Cipher::Vigenere("ky".to_string()), "Thirteen")
+)]
+fn crack_finds_cipher(#[case] ciphertext: &str, #[case] cipher: Cipher, #[case] plaintext: &str) {
+    let decryption = crack(ciphertext).unwrap();
+    assert_eq!(decryption.cipher, cipher);
+    assert_eq!(decryption.plaintext, plaintext);
+    assert_eq!(decryption.reason, explain(plaintext).unwrap());
+}
+
+#[rstest]
+#[case("fourteen")]
+#[case("sbhegrra")]
+#[case("")]
+// Too short for a key of 2 to repeat
+#[case("xyz")]
+// "trece" with the key "aq", but a key of 2 must fit 3 times
+#[case("these")]
+// Already thirteen, and ROT13 does not change it
+#[case("13")]
+fn crack_finds_nothing(#[case] ciphertext: &str) {
+    assert_eq!(crack(ciphertext), None);
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn vigenere_keys_repeat() {
+    // Every 3-letter entry would decrypt "xyz" with some 3-letter key
+    assert!(vigenere_keys("xyz").is_empty());
+    assert!(vigenere_keys("guvegrra").is_empty());
+    assert!(vigenere_keys("dfspdcol").contains(&"ky".to_string()));
+    // A key of 3 fits fewer than 3 times in 8 letters
+    assert!(!vigenere_keys("dlgbxcor").contains(&"key".to_string()));
+}
+
+#[rstest]
+#[case(Cipher::Caesar(13), "ROT13")]
+#[case(Cipher::Caesar(3), "Caesar shift 3")]
+#[case(Cipher::Rot47, "ROT47")]
+#[case(Cipher::Atbash, "Atbash")]
+#[case(Cipher::Vigenere("key".to_string()), "Vigenère with key \"key\"")]
+fn display(#[case] cipher: Cipher, #[case] expected: &str) {
+    assert_eq!(cipher.to_string(), expected);
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn encrypted() {
+    assert!(Encrypted("guvegrra").thirteen());
+    assert!(Encrypted("`b").thirteen());
+    assert!(!Encrypted("13").thirteen());
+    assert!(!Encrypted("fourteen").thirteen());
+}
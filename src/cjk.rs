@@ -0,0 +1,158 @@
+//! Chinese numerals. [`parse`] reads the common and financial characters, Traditional and
+//! Simplified, Suzhou numerals, counting rods and Arabic digits, in any mix, so the value can be
+//! compared with `thirteen()` and the other wrappers. [`ChineseNumeral`] checks a string for 13.
+//!
+//! ```
+//! use is_thirteen::cjk::{parse, ChineseNumeral};
+//! use is_thirteen::{GreaterThan, IsThirteen};
+//!
+//! assert_eq!(parse("壹拾叁"), Some(13));
+//! assert_eq!(parse("二〇一三年"), Some(2013));
+//! assert_eq!(parse("三億五千萬"), Some(350_000_000));
+//! assert!(parse("十三個").unwrap().thirteen());
+//! assert!(GreaterThan(parse("十四").unwrap()).thirteen());
+//! assert!(ChineseNumeral("〡〣").thirteen());
+//! assert!(ChineseNumeral("1十3").thirteen());
+//! ```
+
+use crate::IsThirteen;
+
+/// Counters and measure words that can follow a number, which [`parse`] ignores.
+const COUNTERS: &[char] = &[
+    '個', '个', '箇', '只', '隻', '人', '位', '名', '歲', '岁', '号', '號', '次', '回', '年', '月',
+    '日', '天', '本', '張', '张', '件', '條', '条', '塊', '块', '元', '圓', '圆', '頁', '页', '章',
+    '層', '层', '樓', '楼', '度', '分', '秒',
+];
+
+/// `ChineseNumeral` is thirteen if [`parse`] reads it as 13.
+#[derive(Debug, Clone, Copy)]
+pub struct ChineseNumeral<'s>(pub &'s str);
+
+impl IsThirteen for ChineseNumeral<'_> {
+    fn thirteen(&self) -> bool {
+        parse(self.0) == Some(13)
+    }
+}
+
+/// A character of a numeral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Digit(i64),
+    /// 十, 百 or 千, which multiply the digit before them.
+    Unit(i64),
+    /// 万, 亿 or 兆, which multiply everything before them that is smaller.
+    BigUnit(i64),
+    /// 廿, 卅 and 卌, and the Suzhou tens, which are a digit and 十 in one character.
+    Tens(i64),
+}
+
+fn token(c: char) -> Option<Token> {
+    let digit = match c {
+        '〇' | '○' | '零' => 0,
+        '一' | '壹' | '壱' | '弌' | '幺' | '〡' => 1,
+        '二' | '贰' | '貳' | '弐' | '弍' | '两' | '兩' | '〢' => 2,
+        '三' | '叁' | '參' | '叄' | '参' | '弎' | '〣' => 3,
+        '四' | '肆' | '〤' => 4,
+        '五' | '伍' | '〥' => 5,
+        '六' | '陆' | '陸' | '〦' => 6,
+        '七' | '柒' | '〧' => 7,
+        '八' | '捌' | '〨' => 8,
+        '九' | '玖' | '〩' => 9,
+        // Counting rods, with the vertical forms for units and the horizontal forms for tens
+        '\u{1d360}'..='\u{1d368}' => c as i64 - 0x1d360 + 1,
+        '\u{1d369}'..='\u{1d371}' => c as i64 - 0x1d369 + 1,
+        '０'..='９' => c as i64 - '０' as i64,
+        '0'..='9' => c as i64 - '0' as i64,
+        '十' | '拾' => return Some(Token::Unit(10)),
+        '百' | '佰' => return Some(Token::Unit(100)),
+        '千' | '仟' => return Some(Token::Unit(1000)),
+        '万' | '萬' => return Some(Token::BigUnit(10_000)),
+        '亿' | '億' => return Some(Token::BigUnit(100_000_000)),
+        '兆' => return Some(Token::BigUnit(1_000_000_000_000)),
+        '〸' => return Some(Token::Tens(10)),
+        '廿' | '〹' => return Some(Token::Tens(20)),
+        '卅' | '〺' => return Some(Token::Tens(30)),
+        '卌' => return Some(Token::Tens(40)),
+        _ => return None,
+    };
+    Some(Token::Digit(digit))
+}
+
+/// Returns the value of the Chinese numeral `s`, or `None` if it is not one. Surrounding
+/// whitespace, a leading `第` or `負`, and a trailing counter like `個` are allowed.
+///
+/// Numerals without units, like `"一三"`, `"〡〣"` or `"二〇一三"`, are read digit by digit.
+/// Otherwise, digits multiply the unit after them, and a last digit right after 百 or a larger
+/// unit is one unit smaller, so `"一百三"` is 130 like `"一百三十"`. 兆 is 10¹², as in Taiwan and
+/// Japan.
+pub fn parse(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let s = s.strip_prefix('第').unwrap_or(s);
+    let (negative, s) = match s.strip_prefix(['負', '负']) {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let s = s.strip_suffix(COUNTERS).unwrap_or(s);
+    let tokens = s.chars().map(token).collect::<Option<Vec<_>>>()?;
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let value = if tokens.iter().all(|token| matches!(token, Token::Digit(_))) {
+        tokens.iter().try_fold(0i64, |value, token| match token {
+            Token::Digit(digit) => value.checked_mul(10)?.checked_add(*digit),
+            _ => None,
+        })?
+    } else {
+        with_units(&tokens)?
+    };
+    Some(if negative { -value } else { value })
+}
+
+fn with_units(tokens: &[Token]) -> Option<i64> {
+    // Everything before the last big unit, everything after it, and the digits not yet multiplied
+    let mut total = 0i64;
+    let mut biggest = 1i64;
+    let mut section = 0i64;
+    let mut digits: Option<i64> = None;
+    for &token in tokens {
+        match token {
+            Token::Digit(digit) => {
+                let value = digits.unwrap_or(0).checked_mul(10)?.checked_add(digit)?;
+                digits = Some(value);
+            }
+            Token::Unit(unit) => {
+                section = section.checked_add(digits.take().unwrap_or(1).checked_mul(unit)?)?;
+            }
+            Token::Tens(tens) => {
+                section = section.checked_add(tens)?;
+            }
+            Token::BigUnit(unit) => {
+                let chunk = section.checked_add(digits.take().unwrap_or(0))?;
+                let chunk = if chunk == 0 && total == 0 { 1 } else { chunk };
+                if unit >= biggest {
+                    total = total.checked_add(chunk)?.checked_mul(unit)?;
+                    biggest = unit;
+                } else {
+                    total = total.checked_add(chunk.checked_mul(unit)?)?;
+                }
+                section = 0;
+            }
+        }
+    }
+
+    if let Some(mut last) = digits {
+        // One digit right after 百 or a larger unit, as in "一百三"
+        if let [.., Token::Unit(unit) | Token::BigUnit(unit), Token::Digit(_)] = tokens {
+            if *unit >= 100 {
+                last = last.checked_mul(unit / 10)?;
+            }
+        }
+        section = section.checked_add(last)?;
+    }
+    total.checked_add(section)
+}
+
+#[cfg(test)]
+#[path = "cjk_test.rs"]
+mod cjk_test;
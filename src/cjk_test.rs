@@ -0,0 +1,60 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case("十三", Some(13))]
+#[case("一十三", Some(13))]
+#[case("拾參", Some(13))]
+#[case("拾叁", Some(13))]
+#[case("拾叄", Some(13))]
+#[case("拾参", Some(13))]
+#[case("壹拾叁", Some(13))]
+#[case("壹拾參圓", Some(13))]
+#[case("十三個", Some(13))]
+#[case("十三个", Some(13))]
+#[case("第十三", Some(13))]
+#[case(" 十三號 ", Some(13))]
+#[case("〡〣", Some(13))]
+#[case("〸〣", Some(13))]
+#[case("\u{1d369}\u{1d362}", Some(13))]
+#[case("一三", Some(13))]
+#[case("13", Some(13))]
+#[case("１３", Some(13))]
+#[case("1十3", Some(13))]
+#[case("十3", Some(13))]
+#[case("負十三", Some(-13))]
+#[case("十", Some(10))]
+#[case("廿三", Some(23))]
+#[case("〹〢", Some(22))]
+#[case("卅", Some(30))]
+#[case("一百零三", Some(103))]
+#[case("一百一十", Some(110))]
+#[case("一百三", Some(130))]
+#[case("三千五", Some(3500))]
+#[case("两万三", Some(23_000))]
+#[case("兩萬零三百", Some(20_300))]
+#[case("一万三千", Some(13_000))]
+#[case("13万", Some(130_000))]
+#[case("万", Some(10_000))]
+#[case("三億五千萬", Some(350_000_000))]
+#[case("一万亿", Some(1_000_000_000_000))]
+#[case("十三兆", Some(13_000_000_000_000))]
+#[case("二〇一三年", Some(2013))]
+#[case("〇", Some(0))]
+#[case("", None)]
+#[case("個", None)]
+#[case("十三a", None)]
+#[case("thirteen", None)]
+#[case("九九九九九九九九九九九九九九九九九九九九", None)]
+fn parse_numerals(#[case] s: &str, #[case] expected: Option<i64>) {
+    assert_eq!(parse(s), expected);
+}
+
+#[rstest]
+#[case("壹拾叁", true)]
+#[case("〡〣", true)]
+#[case("十四", false)]
+#[case("", false)]
+fn chinese_numeral(#[case] s: &str, #[case] expected: bool) {
+    assert_eq!(ChineseNumeral(s).thirteen(), expected);
+}
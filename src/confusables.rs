@@ -0,0 +1,147 @@
+//! Detection of strings that look like thirteen strings. [`LooksLike`] compares the confusable
+//! skeletons of [Unicode Technical Standard #39](https://www.unicode.org/reports/tr39/), which
+//! map characters that look alike, like Cyrillic `"т"` and Latin `"t"`, to the same prototype.
+//! [`report`] also lists the characters that were replaced, for anti-spoofing checks.
+//!
+//! ```
+//! # #[cfg(all(feature = "numerals", feature = "languages"))] {
+//! use is_thirteen::confusables::{report, LooksLike};
+//! use is_thirteen::{IsThirteen, Reason};
+//!
+//! assert!(LooksLike("ＸＩＩＩ").thirteen());
+//! assert_eq!(
+//!     LooksLike("\u{442}hirteen").explain(),
+//!     Some(Reason::LooksLike("thirteen".into()))
+//! );
+//!
+//! let report = report("\u{442}hirteen");
+//! assert_eq!(report.entry, Some("thirteen"));
+//! assert_eq!(report.confusables[0].character, '\u{442}');
+//! assert!(report.mixed_script);
+//! # }
+//! ```
+
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use crate::{explain, IsThirteen, Reason};
+use fnv::FnvHashMap as HashMap;
+use once_cell::sync::OnceCell;
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::confusable_detection;
+use unicode_security::mixed_script::MixedScript;
+
+/// `LooksLike` is thirteen if it is thirteen, or if it has the same [`skeleton`] as an entry of
+/// [`THIRTEEN_STRINGS`], `"13"` or `"B"`.
+#[derive(Debug, Clone, Copy)]
+pub struct LooksLike<'s>(pub &'s str);
+
+impl LooksLike<'_> {
+    /// Returns why the string is thirteen, or `None` if it is not. Strings that are thirteen as
+    /// written get the same reason as from [`explain`](crate::explain), and other strings get
+    /// [`Reason::LooksLike`] with what they look like.
+    pub fn explain(&self) -> Option<Reason> {
+        explain(self.0)
+            .or_else(|| lookalike(self.0).map(|entry| Reason::LooksLike(Cow::Borrowed(entry))))
+    }
+}
+
+impl IsThirteen for LooksLike<'_> {
+    fn thirteen(&self) -> bool {
+        self.explain().is_some()
+    }
+}
+
+/// Returns the confusable skeleton of `s`, ignoring case. Characters are uppercased first because
+/// more lowercase letters have a prototype that is not a Latin letter: Cyrillic `"т"` becomes a
+/// small capital `"ᴛ"`, but `"Т"` becomes `"T"`. Characters whose uppercase version is longer,
+/// like `"ß"`, are kept.
+pub fn skeleton(s: &str) -> String {
+    let uppercase: String = s.chars().map(uppercase).collect();
+    confusable_detection::skeleton(&uppercase).collect()
+}
+
+fn uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+/// What [`report`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The entry, `"13"` or `"B"` that the string looks like, if any. Strings that are thirteen as
+    /// written are reported too.
+    pub entry: Option<&'static str>,
+    /// The characters that are not ASCII and have a different prototype, in order.
+    pub confusables: Vec<Confusable>,
+    /// Whether the string mixes scripts, like Latin and Cyrillic.
+    pub mixed_script: bool,
+}
+
+/// A character that looks like another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Confusable {
+    /// The byte offset of the character.
+    pub offset: usize,
+    pub character: char,
+    /// What the character looks like, as it appears in skeletons but composed.
+    pub prototype: String,
+}
+
+/// Reports what `s` looks like and which of its characters are confusable.
+pub fn report(s: &str) -> Report {
+    let confusables = s
+        .char_indices()
+        .filter(|(_, c)| !c.is_ascii())
+        .filter_map(|(offset, character)| {
+            // Skeletons are decomposed, but accented letters are not confusable with themselves
+            let skeleton: String =
+                confusable_detection::skeleton(character.encode_utf8(&mut [0; 4])).collect();
+            let prototype: String = skeleton.nfc().collect();
+            let mut chars = prototype.chars();
+            if chars.next() == Some(character) && chars.next().is_none() {
+                return None;
+            }
+            Some(Confusable {
+                offset,
+                character,
+                prototype,
+            })
+        })
+        .collect();
+    Report {
+        entry: lookalike(s),
+        confusables,
+        mixed_script: !s.is_single_script(),
+    }
+}
+
+/// Returns the target with the same skeleton as `s`.
+fn lookalike(s: &str) -> Option<&'static str> {
+    static SKELETONS: OnceCell<HashMap<String, &'static str>> = OnceCell::new();
+    let skeletons = SKELETONS.get_or_init(|| {
+        let mut entries: Vec<(u8, &'static str)> = THIRTEEN_STRINGS
+            .iter()
+            .map(|&entry| {
+                let category = THIRTEEN_STRINGS.category(entry);
+                (category.map_or(0, |category| category.bits()), entry)
+            })
+            .collect();
+        // Entries that look the same are reported in the first category, after the literals
+        entries.push((0, "13"));
+        entries.push((0, "B"));
+        entries.sort_unstable();
+        let mut skeletons = HashMap::default();
+        for (_, entry) in entries {
+            skeletons.entry(skeleton(entry)).or_insert(entry);
+        }
+        skeletons
+    });
+    skeletons.get(&skeleton(s)).copied()
+}
+
+#[cfg(test)]
+#[path = "confusables_test.rs"]
+mod confusables_test;
@@ -0,0 +1,70 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[cfg_attr(
+    feature = "languages",
+    case("\u{442}hirteen", Some(Reason::LooksLike("thirteen".into())))
+)]
+#[cfg_attr(
+    feature = "languages",
+    case("\u{422}HIRTEEN", Some(Reason::LooksLike("thirteen".into())))
+)]
+#[cfg_attr(feature = "numerals", case("ＸＩＩＩ", Some(Reason::LooksLike("xiii".into()))))]
+#[cfg_attr(feature = "numerals", case("ΧΙΙΙ", Some(Reason::LooksLike("xiii".into()))))]
+#[cfg_attr(
+    feature = "languages",
+    case("thirt\u{435}\u{435}n", Some(Reason::LooksLike("thirteen".into())))
+)]
+#[case("Ｂ", Some(Reason::LooksLike("B".into())))]
+#[cfg_attr(feature = "leetspeak", case("Β", Some(Reason::Entry("β".into()))))]
+#[cfg_attr(feature = "leetspeak", case("ƖƐ", Some(Reason::Entry("ɩɛ".into()))))]
+#[cfg_attr(feature = "numerals", case("XIII", Some(Reason::Entry("xiii".into()))))]
+#[case("ss", None)]
+#[case("fourteen", None)]
+fn looks_like(#[case] s: &str, #[case] expected: Option<Reason>) {
+    assert_eq!(LooksLike(s).thirteen(), expected.is_some());
+    assert_eq!(LooksLike(s).explain(), expected);
+}
+
+#[rstest]
+#[case("thirteen", "THlRTEEN")]
+#[case("\u{442}hirteen", "THlRTEEN")]
+#[case("ß", "ß")]
+#[case("13", "l3")]
+fn skeletons(#[case] s: &str, #[case] expected: &str) {
+    assert_eq!(skeleton(s), expected);
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn reports() {
+    let report = report("\u{442}h\u{456}rteen");
+    assert_eq!(report.entry, Some("thirteen"));
+    assert!(report.mixed_script);
+    assert_eq!(
+        report.confusables,
+        [
+            Confusable {
+                offset: 0,
+                character: '\u{442}',
+                prototype: "ᴛ".to_string(),
+            },
+            Confusable {
+                offset: 3,
+                character: '\u{456}',
+                prototype: "i".to_string(),
+            },
+        ]
+    );
+
+    let report = super::report("fourteen");
+    assert_eq!(report.entry, None);
+    assert!(report.confusables.is_empty());
+    assert!(!report.mixed_script);
+
+    // Characters that are their own prototype are not reported
+    let report = super::report("þrettán");
+    assert_eq!(report.entry, Some("þrettán"));
+    assert!(report.confusables.is_empty());
+}
@@ -0,0 +1,372 @@
+//! Decoding of encoded strings. The dataset lists a few encodings of thirteen strings by hand,
+//! like `"mtm="`, but not `"MTM"` without padding, `"%31%33"` or `"&#x31;&#x33;"`. A [`Decoder`]
+//! tries every [`Encoding`] on a string, then on what that decodes to, up to a maximum depth, and
+//! reports the shortest chain of decodings that ends in a thirteen string.
+//!
+//! ```
+//! use is_thirteen::decoder::{Decoder, Encoded, Encoding};
+//! use is_thirteen::IsThirteen;
+//!
+//! let decoded = Decoder::new().decode("MzEzMw").unwrap();
+//! assert_eq!(decoded.chain, [Encoding::Base64, Encoding::Hex]);
+//! assert_eq!(decoded.to_string(), "base64 → hex → \"13\"");
+//!
+//! assert!(Encoded("&#49;&#51;").thirteen());
+//! assert!(Encoded("GEZQ====").thirteen());
+//! assert!(!Encoded("MTQ=").thirteen());
+//! ```
+
+use crate::{explain, IsThirteen, Reason};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+/// Every encoding, in the order they are tried. Escapes come first, since strings of hex digits
+/// are also Base32 or Base64.
+const ENCODINGS: [Encoding; 7] = [
+    Encoding::Percent,
+    Encoding::HtmlEntities,
+    Encoding::Escapes,
+    Encoding::QuotedPrintable,
+    Encoding::Hex,
+    Encoding::Base32,
+    Encoding::Base64,
+];
+
+/// An encoding that [`Decoder`] tries, in the order they are listed. Every encoding must decode
+/// to UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// `%XX` escapes, as in URLs. Needs at least one escape.
+    Percent,
+    /// `&#DD;`, `&#xHH;` and the named entities of XML, plus `&nbsp;`. Needs at least one entity.
+    HtmlEntities,
+    /// `\xHH`, `\uHHHH` and `\u{H…}` escapes, as in string literals. Needs at least one escape.
+    Escapes,
+    /// `=XX` escapes and `=` at the end of a line, as in email. Needs at least one escape.
+    QuotedPrintable,
+    /// Pairs of hex digits in any case, optionally separated by spaces or colons.
+    Hex,
+    /// RFC 4648 Base32 in any case, with or without padding.
+    Base32,
+    /// Standard or URL-safe Base64, with or without padding.
+    Base64,
+}
+
+impl Encoding {
+    /// Decodes `s` once, or returns `None` if it is not in this encoding or does not decode to
+    /// UTF-8.
+    pub fn decode(self, s: &str) -> Option<String> {
+        let bytes = match self {
+            Encoding::Percent => unescape(s, "%", |rest| hex_byte(rest).map(|b| (Some(b), 2)))?,
+            Encoding::HtmlEntities => return html_entities(s),
+            Encoding::Escapes => return escapes(s),
+            Encoding::QuotedPrintable => quoted_printable(s)?,
+            Encoding::Hex => hex(s)?,
+            Encoding::Base32 => base32(s)?,
+            Encoding::Base64 => decode_base64(s)?,
+        };
+        String::from_utf8(bytes).ok()
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Encoding::Percent => "percent-encoding",
+            Encoding::HtmlEntities => "HTML entities",
+            Encoding::Escapes => "escapes",
+            Encoding::QuotedPrintable => "quoted-printable",
+            Encoding::Hex => "hex",
+            Encoding::Base32 => "base32",
+            Encoding::Base64 => "base64",
+        })
+    }
+}
+
+/// A chain of decodings that ends in a thirteen string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// The encodings that were decoded, outermost first.
+    pub chain: Vec<Encoding>,
+    pub decoded: String,
+    /// Why `decoded` is thirteen.
+    pub reason: Reason,
+}
+
+impl fmt::Display for Decoded {
+    /// Writes the chain and the result, like `base64 → hex → "13"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for encoding in &self.chain {
+            write!(f, "{} → ", encoding)?;
+        }
+        write!(f, "{:?}", self.decoded)
+    }
+}
+
+/// Decodes strings layer by layer and checks each result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decoder {
+    max_depth: usize,
+}
+
+impl Decoder {
+    /// Returns a decoder that decodes up to 3 layers.
+    pub const fn new() -> Self {
+        Self { max_depth: 3 }
+    }
+
+    /// Sets how many layers of encoding are decoded at most.
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the shortest chain of at least one decoding that turns `s` into a thirteen string.
+    /// Chains of the same length are tried in the order of [`Encoding`]'s variants.
+    pub fn decode(&self, s: &str) -> Option<Decoded> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((s.to_string(), Vec::new()));
+        while let Some((text, chain)) = queue.pop_front() {
+            if chain.len() == self.max_depth {
+                continue;
+            }
+            for &encoding in &ENCODINGS {
+                let decoded = match encoding.decode(&text) {
+                    Some(decoded) if decoded != text && seen.insert(decoded.clone()) => decoded,
+                    _ => continue,
+                };
+                let mut chain = chain.clone();
+                chain.push(encoding);
+                if let Some(reason) = explain(&decoded) {
+                    return Some(Decoded {
+                        chain,
+                        decoded,
+                        reason,
+                    });
+                }
+                queue.push_back((decoded, chain));
+            }
+        }
+        None
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `Encoded` is thirteen if the default [`Decoder`] decodes it to a thirteen string.
+#[derive(Debug, Clone, Copy)]
+pub struct Encoded<'s>(pub &'s str);
+
+impl IsThirteen for Encoded<'_> {
+    fn thirteen(&self) -> bool {
+        Decoder::new().decode(self.0).is_some()
+    }
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|digit| digit as u8)
+}
+
+/// Reads two hex digits at the start of `s`.
+fn hex_byte(s: &str) -> Option<u8> {
+    match s.as_bytes() {
+        [high, low, ..] => Some(hex_digit(*high)? << 4 | hex_digit(*low)?),
+        _ => None,
+    }
+}
+
+/// Replaces every `marker` in `s` and what follows it with the byte, if any, that `escape` reads
+/// from the rest of the string, along with how many bytes it used. Fails if `s` has no escape, or
+/// if `escape` fails.
+fn unescape<F>(s: &str, marker: &str, mut escape: F) -> Option<Vec<u8>>
+where
+    F: FnMut(&str) -> Option<(Option<u8>, usize)>,
+{
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s;
+    let mut escaped = false;
+    while let Some(i) = rest.find(marker) {
+        bytes.extend_from_slice(&rest.as_bytes()[..i]);
+        let (byte, len) = escape(&rest[i + marker.len()..])?;
+        bytes.extend(byte);
+        escaped = true;
+        rest = &rest[i + marker.len() + len..];
+    }
+    bytes.extend_from_slice(rest.as_bytes());
+    if escaped {
+        Some(bytes)
+    } else {
+        None
+    }
+}
+
+fn quoted_printable(s: &str) -> Option<Vec<u8>> {
+    unescape(s, "=", |rest| {
+        // A soft line break, which is not part of the text
+        if rest.starts_with("\r\n") {
+            Some((None, 2))
+        } else if rest.starts_with('\n') {
+            Some((None, 1))
+        } else {
+            hex_byte(rest).map(|b| (Some(b), 2))
+        }
+    })
+}
+
+fn html_entities(s: &str) -> Option<String> {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut escaped = false;
+    while let Some(i) = rest.find('&') {
+        decoded.push_str(&rest[..i]);
+        let end = rest[i..].find(';')? + i;
+        let entity = &rest[i + 1..end];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            _ => {
+                let number = entity.strip_prefix('#')?;
+                let code = match number
+                    .strip_prefix('x')
+                    .or_else(|| number.strip_prefix('X'))
+                {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)?
+            }
+        };
+        decoded.push(c);
+        escaped = true;
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    if escaped {
+        Some(decoded)
+    } else {
+        None
+    }
+}
+
+fn escapes(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut rest = s;
+    let mut escaped = false;
+    while let Some(i) = rest.find('\\') {
+        bytes.extend_from_slice(&rest.as_bytes()[..i]);
+        let escape = &rest[i + 1..];
+        let len = if let Some(hex) = escape.strip_prefix('x') {
+            // Bytes, which can spell multibyte characters together
+            bytes.push(hex_byte(hex)?);
+            3
+        } else if let Some(braced) = escape.strip_prefix("u{") {
+            let end = braced.find('}')?;
+            let c = char::from_u32(u32::from_str_radix(&braced[..end], 16).ok()?)?;
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            end + 3
+        } else if let Some(hex) = escape.strip_prefix('u') {
+            let c = char::from_u32(u32::from_str_radix(hex.get(..4)?, 16).ok()?)?;
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            5
+        } else {
+            return None;
+        };
+        escaped = true;
+        rest = &rest[i + 1 + len..];
+    }
+    bytes.extend_from_slice(rest.as_bytes());
+    if escaped {
+        String::from_utf8(bytes).ok()
+    } else {
+        None
+    }
+}
+
+fn hex(s: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = s
+        .bytes()
+        .filter(|&b| !matches!(b, b' ' | b':'))
+        .map(hex_digit)
+        .collect::<Option<_>>()?;
+    if digits.is_empty() || digits.len() % 2 == 1 {
+        return None;
+    }
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect(),
+    )
+}
+
+/// Decodes the digits in `values`, which have `bits_per_value` bits each. Returns `None` if the
+/// bits left over after the last byte are not zero, since encoders never write that.
+fn decode_bits<I>(values: I, bits_per_value: u32) -> Option<Vec<u8>>
+where
+    I: IntoIterator<Item = Option<u8>>,
+{
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for value in values {
+        buffer = buffer << bits_per_value | u32::from(value?);
+        bits += bits_per_value;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    if bits >= bits_per_value || buffer != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+fn base32(s: &str) -> Option<Vec<u8>> {
+    let s = s.trim_end_matches('=');
+    if s.is_empty() {
+        return None;
+    }
+    decode_bits(
+        s.bytes().map(|b| match b.to_ascii_uppercase() {
+            b @ b'A'..=b'Z' => Some(b - b'A'),
+            b @ b'2'..=b'7' => Some(b - b'2' + 26),
+            _ => None,
+        }),
+        5,
+    )
+}
+
+/// Decodes standard or URL-safe Base64, with or without padding. Returns `None` if the bits left
+/// over after the last byte are not zero, since encoders never write that.
+pub(crate) fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s = s
+        .strip_suffix("==")
+        .or_else(|| s.strip_suffix('='))
+        .unwrap_or(s);
+    decode_bits(
+        s.bytes().map(|b| match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a' + 26),
+            b'0'..=b'9' => Some(b - b'0' + 52),
+            b'+' | b'-' => Some(62),
+            b'/' | b'_' => Some(63),
+            _ => None,
+        }),
+        6,
+    )
+}
+
+#[cfg(test)]
+#[path = "decoder_test.rs"]
+mod decoder_test;
@@ -0,0 +1,102 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case(Encoding::Percent, "%31%33", Some("13"))]
+#[case(Encoding::Percent, "thir%74een", Some("thirteen"))]
+#[case(Encoding::Percent, "%C3%BE", Some("þ"))]
+#[case(Encoding::Percent, "%FF", None)]
+#[case(Encoding::Percent, "%3", None)]
+#[case(Encoding::Percent, "13", None)]
+#[case(Encoding::HtmlEntities, "&#49;&#51;", Some("13"))]
+#[case(Encoding::HtmlEntities, "&#x31;&#X33;", Some("13"))]
+#[case(Encoding::HtmlEntities, "baker&apos;s dozen", Some("baker's dozen"))]
+#[case(Encoding::HtmlEntities, "&#xfe;rett&aacute;n", None)]
+#[case(Encoding::HtmlEntities, "&#49", None)]
+#[case(Encoding::Escapes, "\\x31\\x33", Some("13"))]
+#[case(Encoding::Escapes, "\\u0031\\u{33}", Some("13"))]
+#[case(Encoding::Escapes, "\\xc3\\xberett\\u00e1n", Some("þrettán"))]
+#[case(Encoding::Escapes, "\\n", None)]
+#[case(Encoding::QuotedPrintable, "=31=33", Some("13"))]
+#[case(Encoding::QuotedPrintable, "thir=\r\nteen", Some("thirteen"))]
+#[case(Encoding::QuotedPrintable, "=C3=BErett=C3=A1n", Some("þrettán"))]
+#[case(Encoding::QuotedPrintable, "a=b", None)]
+#[case(Encoding::Hex, "3133", Some("13"))]
+#[case(Encoding::Hex, "74 68 69 72 74 65 65 6E", Some("thirteen"))]
+#[case(Encoding::Hex, "31:33", Some("13"))]
+#[case(Encoding::Hex, "313", None)]
+#[case(Encoding::Hex, "", None)]
+#[case(Encoding::Base32, "GEZQ====", Some("13"))]
+#[case(Encoding::Base32, "gezq", Some("13"))]
+#[case(Encoding::Base32, "ORUGS4TUMVSW4", Some("thirteen"))]
+#[case(Encoding::Base32, "GEZR", None)]
+#[case(Encoding::Base32, "GEZ", None)]
+#[case(Encoding::Base64, "MTM=", Some("13"))]
+#[case(Encoding::Base64, "MTM", Some("13"))]
+#[case(Encoding::Base64, "dGhpcnRlZW4", Some("thirteen"))]
+#[case(Encoding::Base64, "MTN", None)]
+#[case(Encoding::Base64, "M", None)]
+#[case(Encoding::Base64, "MT.M", None)]
+fn decode_once(#[case] encoding: Encoding, #[case] s: &str, #[case] expected: Option<&str>) {
+    assert_eq!(encoding.decode(s).as_deref(), expected);
+}
+
+#[rstest]
+#[case("", Some(vec![]))]
+#[case("MTM=", Some(b"13".to_vec()))]
+#[case("-_8", Some(vec![0xfb, 0xff]))]
+#[case("MTN", None)]
+fn base64_bytes(#[case] s: &str, #[case] expected: Option<Vec<u8>>) {
+    assert_eq!(decode_base64(s), expected);
+}
+
+#[rstest]
+#[case("MTM", &[Encoding::Base64], "13")]
+#[case("%31%33", &[Encoding::Percent], "13")]
+#[case("&#x31;&#x33;", &[Encoding::HtmlEntities], "13")]
+#[case("MzEzMw==", &[Encoding::Base64, Encoding::Hex], "13")]
+#[case("%4D%54%4D", &[Encoding::Percent, Encoding::Base64], "13")]
+// "MTM=" is an entry, but "MTM" is not
+#[cfg_attr(feature = "encodings", case("TVRNPQ", &[Encoding::Base64], "MTM="))]
+#[case("TVRN", &[Encoding::Base64, Encoding::Base64], "13")]
+#[case("EUZTCJJTGM", &[Encoding::Base32, Encoding::Percent], "13")]
+fn decoder(#[case] s: &str, #[case] chain: &[Encoding], #[case] decoded: &str) {
+    let found = Decoder::new().decode(s).unwrap();
+    assert_eq!(found.chain, chain);
+    assert_eq!(found.decoded, decoded);
+    assert_eq!(found.reason, explain(decoded).unwrap());
+}
+
+#[rstest]
+#[case("13")]
+#[case("thirteen")]
+#[case("MTQ=")]
+#[case("")]
+fn decoder_finds_nothing(#[case] s: &str) {
+    assert_eq!(Decoder::new().decode(s), None);
+}
+
+#[test]
+fn max_depth() {
+    // base64 → base64 → base64 → base64 → "13"
+    let s = "VkZaU1RnPT0=";
+    assert_eq!(Decoder::new().decode(s), None);
+    assert_eq!(
+        Decoder::new().max_depth(4).decode(s).unwrap().chain.len(),
+        4
+    );
+    assert_eq!(Decoder::new().max_depth(0).decode("MTM="), None);
+}
+
+#[test]
+fn display() {
+    let decoded = Decoder::new().decode("MzEzMw==").unwrap();
+    assert_eq!(decoded.to_string(), "base64 → hex → \"13\"");
+    assert_eq!(Encoding::HtmlEntities.to_string(), "HTML entities");
+}
+
+#[test]
+fn encoded() {
+    assert!(Encoded("%31%33").thirteen());
+    assert!(!Encoded("%31%34").thirteen());
+}
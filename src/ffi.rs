@@ -0,0 +1,138 @@
+//! C API. The header is `include/is_thirteen.h`.
+//!
+//! Strings are passed as a pointer and a length in bytes and do not need to be NUL-terminated.
+//! Null pointers and invalid UTF-8 are never thirteen.
+
+use crate::{
+    AnagramOf, AtomicNumber, Backwards, CanSpell, DivisibleBy, GreaterThan, IsThirteen, LessThan,
+    Roughly, Within,
+};
+use std::os::raw::c_char;
+
+/// Converts a C string to `&str`.
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+unsafe fn to_str<'a>(s: *const c_char, len: usize) -> Option<&'a str> {
+    if s.is_null() {
+        None
+    } else {
+        std::str::from_utf8(std::slice::from_raw_parts(s as *const u8, len)).ok()
+    }
+}
+
+/// Returns `true` if the string is thirteen.
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_thirteen_str(s: *const c_char, len: usize) -> bool {
+    to_str(s, len).is_some_and(|s| s.thirteen())
+}
+
+/// Returns `true` if `n == 13`.
+#[no_mangle]
+pub extern "C" fn is_thirteen_i64(n: i64) -> bool {
+    n.thirteen()
+}
+
+/// Returns `true` if `n` is approximately 13.
+#[no_mangle]
+pub extern "C" fn is_thirteen_f64(n: f64) -> bool {
+    n.thirteen()
+}
+
+/// Returns `true` if `c` is a thirteen character. Values that are not Unicode scalar values are
+/// not thirteen.
+#[no_mangle]
+pub extern "C" fn is_thirteen_char(c: u32) -> bool {
+    char::from_u32(c).is_some_and(|c| c.thirteen())
+}
+
+/// Returns `true` if `n` is in [12.5, 13.5). See [`Roughly`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_roughly(n: f64) -> bool {
+    Roughly(n).thirteen()
+}
+
+/// Returns `true` if `n` is at most `radius` away from 13. See [`Within`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_within(n: f64, radius: f64) -> bool {
+    Within::new(n, radius).thirteen()
+}
+
+/// Returns `true` if 13 divides `n`. See [`DivisibleBy`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_divisible_by_i64(n: i64) -> bool {
+    DivisibleBy(n).thirteen()
+}
+
+/// Returns `true` if 13 divides `n`. See [`DivisibleBy`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_divisible_by_f64(n: f64) -> bool {
+    DivisibleBy(n).thirteen()
+}
+
+/// Returns `true` if `n > 13`. See [`GreaterThan`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_greater_than_i64(n: i64) -> bool {
+    GreaterThan(n).thirteen()
+}
+
+/// Returns `true` if `n > 13`. See [`GreaterThan`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_greater_than_f64(n: f64) -> bool {
+    GreaterThan(n).thirteen()
+}
+
+/// Returns `true` if `n < 13`. See [`LessThan`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_less_than_i64(n: i64) -> bool {
+    LessThan(n).thirteen()
+}
+
+/// Returns `true` if `n < 13`. See [`LessThan`].
+#[no_mangle]
+pub extern "C" fn is_thirteen_less_than_f64(n: f64) -> bool {
+    LessThan(n).thirteen()
+}
+
+/// Returns `true` if the string contains the letters of "thirteen". See [`CanSpell`].
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_thirteen_can_spell(s: *const c_char, len: usize) -> bool {
+    to_str(s, len).is_some_and(|s| CanSpell::new(s).thirteen())
+}
+
+/// Returns `true` if the string is an anagram of "thirteen". See [`AnagramOf`].
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_thirteen_anagram_of(s: *const c_char, len: usize) -> bool {
+    to_str(s, len).is_some_and(|s| AnagramOf::new(s).thirteen())
+}
+
+/// Returns `true` if the string is "thirteen" spelled backwards. See [`Backwards`].
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_thirteen_backwards(s: *const c_char, len: usize) -> bool {
+    to_str(s, len).is_some_and(|s| Backwards(s).thirteen())
+}
+
+/// Returns `true` if the string is "aluminum". See [`AtomicNumber`].
+///
+/// # Safety
+/// `s` must be null or point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn is_thirteen_atomic_number(s: *const c_char, len: usize) -> bool {
+    to_str(s, len).is_some_and(|s| AtomicNumber(s).thirteen())
+}
+
+#[cfg(test)]
+#[path = "ffi_test.rs"]
+mod ffi_test;
@@ -0,0 +1,67 @@
+use super::*;
+use rstest::rstest;
+use std::ptr::null;
+
+fn with_str<F: Fn(*const c_char, usize) -> bool>(s: &[u8], f: F) -> bool {
+    f(s.as_ptr() as *const c_char, s.len())
+}
+
+#[rstest]
+#[case(b"13", true)]
+#[case("十三".as_bytes(), true)]
+#[case(b"Thirteen", true)]
+#[case(b"13 and more", false)]
+#[case(b"", false)]
+#[case(b"\xff\xfe", false)]
+fn test_is_thirteen_str(#[case] s: &[u8], #[case] expected: bool) {
+    assert_eq!(
+        with_str(s, |ptr, len| unsafe { is_thirteen_str(ptr, len) }),
+        expected
+    );
+}
+
+#[test]
+fn null_str() {
+    unsafe {
+        assert!(!is_thirteen_str(null(), 0));
+        assert!(!is_thirteen_can_spell(null(), 13));
+        assert!(!is_thirteen_anagram_of(null(), 13));
+        assert!(!is_thirteen_backwards(null(), 13));
+        assert!(!is_thirteen_atomic_number(null(), 13));
+    }
+}
+
+#[test]
+fn string_wrappers() {
+    unsafe {
+        assert!(with_str(b"eihbtrtAecdn", |p, l| is_thirteen_can_spell(
+            p, l
+        )));
+        assert!(with_str(b"nRteehit", |p, l| is_thirteen_anagram_of(p, l)));
+        assert!(with_str(b"neetRiht", |p, l| is_thirteen_backwards(p, l)));
+        assert!(with_str(b"Aluminum", |p, l| is_thirteen_atomic_number(
+            p, l
+        )));
+        assert!(!with_str(b"neetRiht", |p, l| is_thirteen_atomic_number(
+            p, l
+        )));
+    }
+}
+
+#[test]
+fn number_wrappers() {
+    assert!(is_thirteen_i64(13));
+    assert!(!is_thirteen_i64(-13));
+    assert!(is_thirteen_f64(13.0));
+    assert!(is_thirteen_char('B' as u32));
+    assert!(!is_thirteen_char(0xd800));
+    assert!(is_thirteen_roughly(12.5));
+    assert!(is_thirteen_within(12.0, 1.0));
+    assert!(!is_thirteen_within(11.0, 1.0));
+    assert!(is_thirteen_divisible_by_i64(26));
+    assert!(is_thirteen_divisible_by_f64(39.0));
+    assert!(is_thirteen_greater_than_i64(14));
+    assert!(!is_thirteen_greater_than_f64(13.0));
+    assert!(is_thirteen_less_than_i64(12));
+    assert!(!is_thirteen_less_than_f64(13.0));
+}
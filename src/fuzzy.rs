@@ -0,0 +1,258 @@
+//! Matching of misspelled thirteen strings. [`Fuzzy`] accepts strings within a few edits of an
+//! entry of the dataset, and [`Matcher::with_fuzzy`] does the same for a matcher. Distances are
+//! Damerau–Levenshtein distances, so inserting, deleting or replacing a character or swapping two
+//! characters are one edit each. Candidates are found with a BK-tree instead of comparing the
+//! input to every entry.
+//!
+//! ```
+//! # #[cfg(feature = "languages")] {
+//! use is_thirteen::fuzzy::{Fuzzy, Tolerance};
+//! use is_thirteen::{IsThirteen, Reason};
+//!
+//! assert!(Fuzzy::new("thirten").thirteen());
+//! assert_eq!(
+//!     Fuzzy::new("Dreizen").explain(),
+//!     Some(Reason::Fuzzy { entry: "dreizehn".into(), distance: 1 })
+//! );
+//! assert!(!Fuzzy::new("fourteen").thirteen());
+//! assert!(!Fuzzy::with_tolerance("thirten", Tolerance::fixed(0)).thirteen());
+//! # }
+//! ```
+//!
+//! [`Matcher::with_fuzzy`]: crate::matcher::Matcher::with_fuzzy
+
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use crate::{explain, fold_case, IsThirteen, Reason};
+use fnv::FnvHashMap as HashMap;
+use once_cell::sync::OnceCell;
+use std::borrow::Cow;
+use std::iter::FromIterator;
+
+/// How many edits a fuzzy match may need, depending on the length of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tolerance {
+    chars_per_edit: usize,
+    max: usize,
+}
+
+impl Tolerance {
+    /// Allows one edit for every `chars_per_edit` characters of input, up to `max` edits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars_per_edit` is 0.
+    pub const fn scaled(chars_per_edit: usize, max: usize) -> Self {
+        assert!(chars_per_edit > 0, "chars_per_edit must be positive");
+        Self {
+            chars_per_edit,
+            max,
+        }
+    }
+
+    /// Allows `edits` edits, but never more than the input has characters.
+    pub const fn fixed(edits: usize) -> Self {
+        Self::scaled(1, edits)
+    }
+
+    /// Returns the number of edits allowed for an input of `len` characters.
+    pub fn max_distance(&self, len: usize) -> usize {
+        (len / self.chars_per_edit).min(self.max)
+    }
+}
+
+impl Default for Tolerance {
+    /// One edit for every 6 characters, up to 3 edits. Inputs shorter than 6 characters must
+    /// match exactly, because common short words like `"trees"` are one edit from short entries
+    /// like `"trese"`.
+    fn default() -> Self {
+        Self::scaled(6, 3)
+    }
+}
+
+/// `Fuzzy` is thirteen if it is thirteen, or if its lowercase version is within the [`Tolerance`]
+/// of an entry of [`THIRTEEN_STRINGS`].
+#[derive(Debug, Clone, Copy)]
+pub struct Fuzzy<'s> {
+    s: &'s str,
+    tolerance: Tolerance,
+}
+
+impl<'s> Fuzzy<'s> {
+    /// Uses the default [`Tolerance`].
+    pub fn new(s: &'s str) -> Self {
+        Self::with_tolerance(s, Tolerance::default())
+    }
+
+    pub fn with_tolerance(s: &'s str, tolerance: Tolerance) -> Self {
+        Self { s, tolerance }
+    }
+
+    /// Returns why the string is thirteen, or `None` if it is not. Strings that are thirteen
+    /// without edits get the same reason as from [`explain`](crate::explain), and other strings
+    /// get [`Reason::Fuzzy`] with the closest entry.
+    pub fn explain(&self) -> Option<Reason> {
+        explain(self.s).or_else(|| {
+            static BUILTIN: OnceCell<BkTree> = OnceCell::new();
+            let tree = BUILTIN.get_or_init(|| {
+                THIRTEEN_STRINGS
+                    .iter()
+                    .map(|&entry| (Cow::Borrowed(entry), Cow::Borrowed(entry)))
+                    .collect()
+            });
+            let mut lowercase = String::new();
+            fold_case(self.s, &mut lowercase);
+            tree.explain(&lowercase, self.tolerance)
+        })
+    }
+}
+
+impl IsThirteen for Fuzzy<'_> {
+    fn thirteen(&self) -> bool {
+        self.explain().is_some()
+    }
+}
+
+/// Returns the Damerau–Levenshtein distance between `a` and `b`, in characters. Unlike the
+/// optimal string alignment distance, a substring may be edited again after two of its characters
+/// are swapped, which makes this a metric.
+pub fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let infinity = a.len() + b.len();
+    // `d[i + 1][j + 1]` is the distance between the first `i` characters of `a` and the first `j`
+    // characters of `b`, and the first row and column are sentinels
+    let width = b.len() + 2;
+    let mut d = vec![0; (a.len() + 2) * width];
+    d[0] = infinity;
+    for i in 0..=a.len() {
+        d[(i + 1) * width] = infinity;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        d[j + 1] = infinity;
+        d[width + j + 1] = j;
+    }
+
+    // The last row of `a` in which each character was seen
+    let mut last_row: HashMap<char, usize> = HashMap::default();
+    for i in 1..=a.len() {
+        let mut last_column = 0;
+        for j in 1..=b.len() {
+            let row = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let column = last_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_column = j;
+                0
+            } else {
+                1
+            };
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(d[row * width + column] + (i - row - 1) + 1 + (j - column - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(a.len() + 1) * width + b.len() + 1]
+}
+
+/// A BK-tree of dataset entries, keyed by the form that input is folded to before lookup.
+#[derive(Debug, Default)]
+pub(crate) struct BkTree {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug)]
+struct Node {
+    key: Cow<'static, str>,
+    entry: Cow<'static, str>,
+    /// The category of `entry` in [`THIRTEEN_STRINGS`], with entries in earlier categories
+    /// first. Entries that are not built in come last.
+    rank: u8,
+    /// The distance to each child and its index in `nodes`.
+    children: Vec<(usize, usize)>,
+}
+
+impl BkTree {
+    /// Adds `entry` under `key`, unless the tree already has `key`.
+    fn insert(&mut self, key: Cow<'static, str>, entry: Cow<'static, str>) {
+        let new = self.nodes.len();
+        let mut current = 0;
+        while current < new {
+            let d = distance(&key, &self.nodes[current].key);
+            if d == 0 {
+                return;
+            }
+            match self.nodes[current]
+                .children
+                .iter()
+                .find(|child| child.0 == d)
+            {
+                Some(&(_, child)) => current = child,
+                None => {
+                    self.nodes[current].children.push((d, new));
+                    break;
+                }
+            }
+        }
+        let rank = THIRTEEN_STRINGS
+            .category(&entry)
+            .map_or(u8::MAX, |category| category.bits());
+        self.nodes.push(Node {
+            key,
+            entry,
+            rank,
+            children: Vec::new(),
+        });
+    }
+
+    /// Returns [`Reason::Fuzzy`] with the entry closest to `folded`, if one is within
+    /// `tolerance`. Ties go to the entry in the earliest category, so that `"thirten"` is
+    /// reported as `"thirteen"` instead of `"thirt3en"`, and then to the smallest entry.
+    pub(crate) fn explain(&self, folded: &str, tolerance: Tolerance) -> Option<Reason> {
+        let max = tolerance.max_distance(folded.chars().count());
+        let mut closest: Option<(usize, u8, &Cow<'static, str>)> = None;
+        let mut stack = vec![0];
+        while let Some(current) = stack.pop() {
+            let node = match self.nodes.get(current) {
+                Some(node) => node,
+                None => break,
+            };
+            let d = distance(folded, &node.key);
+            let candidate = (d, node.rank, &node.entry);
+            if d <= max {
+                closest = Some(closest.map_or(candidate, |closest| closest.min(candidate)));
+            }
+            // By the triangle inequality, only children this far from `node` can be close enough
+            let range = d.saturating_sub(max)..=d + max;
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|(child_distance, _)| range.contains(child_distance))
+                    .map(|&(_, child)| child),
+            );
+        }
+        closest.map(|(distance, _, entry)| Reason::Fuzzy {
+            entry: entry.clone(),
+            distance,
+        })
+    }
+}
+
+impl FromIterator<(Cow<'static, str>, Cow<'static, str>)> for BkTree {
+    /// Builds a tree from `(key, entry)` pairs.
+    fn from_iter<I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (Cow<'static, str>, Cow<'static, str>)>,
+    {
+        let mut tree = Self::default();
+        for (key, entry) in pairs {
+            tree.insert(key, entry);
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+#[path = "fuzzy_test.rs"]
+mod fuzzy_test;
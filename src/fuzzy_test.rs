@@ -0,0 +1,121 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case("", "", 0)]
+#[case("", "abc", 3)]
+#[case("thirteen", "thirteen", 0)]
+#[case("thirten", "thirteen", 1)]
+#[case("thriteen", "thirteen", 1)]
+#[case("dreizen", "dreizehn", 1)]
+#[case("ca", "abc", 2)]
+#[case("fourteen", "thirteen", 3)]
+#[case("þrettán", "threttan", 3)]
+fn damerau_levenshtein(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+    assert_eq!(distance(a, b), expected);
+    assert_eq!(distance(b, a), expected);
+}
+
+#[rstest]
+#[case(Tolerance::default(), 3, 0)]
+#[case(Tolerance::default(), 5, 0)]
+#[case(Tolerance::default(), 7, 1)]
+#[case(Tolerance::default(), 10, 1)]
+#[case(Tolerance::default(), 12, 2)]
+#[case(Tolerance::default(), 40, 3)]
+#[case(Tolerance::fixed(2), 1, 1)]
+#[case(Tolerance::fixed(2), 8, 2)]
+fn max_distance(#[case] tolerance: Tolerance, #[case] len: usize, #[case] expected: usize) {
+    assert_eq!(tolerance.max_distance(len), expected);
+}
+
+#[test]
+#[should_panic(expected = "chars_per_edit must be positive")]
+fn zero_chars_per_edit() {
+    Tolerance::scaled(0, 1);
+}
+
+#[rstest]
+#[cfg_attr(feature = "languages", case("thirten", Some(("thirteen", 1))))]
+#[case("threeteen", None)]
+#[cfg_attr(feature = "languages", case("Dreizen", Some(("dreizehn", 1))))]
+#[cfg_attr(feature = "languages", case("tredecim!", Some(("tredecim", 1))))]
+#[case("trente", None)]
+#[case("fourteen", None)]
+#[case("xiv", None)]
+#[case("", None)]
+#[case("tree", None)]
+#[case("trace", None)]
+#[case("truce", None)]
+#[case("trees", None)]
+#[case("tres", None)]
+#[case("these", None)]
+#[case("thirteenth", None)]
+fn fuzzy(#[case] s: &str, #[case] expected: Option<(&'static str, usize)>) {
+    let expected = expected.map(|(entry, distance)| Reason::Fuzzy {
+        entry: entry.into(),
+        distance,
+    });
+    assert_eq!(Fuzzy::new(s).explain(), expected);
+    assert_eq!(Fuzzy::new(s).thirteen(), expected.is_some());
+}
+
+#[cfg(all(feature = "numerals", feature = "languages"))]
+#[test]
+fn exact_reasons() {
+    assert_eq!(
+        Fuzzy::new("XIII").explain(),
+        Some(Reason::Entry("xiii".into()))
+    );
+    assert_eq!(Fuzzy::new("13").explain(), Some(Reason::Literal));
+    assert!(!Fuzzy::with_tolerance("thirten", Tolerance::fixed(0)).thirteen());
+    assert!(Fuzzy::with_tolerance("thirtn", Tolerance::fixed(2)).thirteen());
+    assert_eq!(
+        Fuzzy::with_tolerance("threeteen", Tolerance::scaled(3, 3)).explain(),
+        Some(Reason::Fuzzy {
+            entry: "thirteen".into(),
+            distance: 3
+        })
+    );
+}
+
+#[test]
+fn same_as_linear_scan() {
+    let tree: BkTree = THIRTEEN_STRINGS
+        .iter()
+        .map(|&entry| (Cow::Borrowed(entry), Cow::Borrowed(entry)))
+        .collect();
+    let tolerance = Tolerance::fixed(3);
+    for query in [
+        "thirtean",
+        "tridici",
+        "dertien",
+        "trzynascie",
+        "xiiii",
+        "quatorze",
+    ] {
+        let mut closest: Vec<(usize, u8, &str)> = THIRTEEN_STRINGS
+            .iter()
+            .map(|&entry| {
+                let category = THIRTEEN_STRINGS.category(entry).unwrap();
+                (distance(query, entry), category.bits(), entry)
+            })
+            .filter(|&(d, _, _)| d <= 3)
+            .collect();
+        closest.sort_unstable();
+        let expected = closest.first().map(|&(distance, _, entry)| Reason::Fuzzy {
+            entry: entry.into(),
+            distance,
+        });
+        assert_eq!(tree.explain(query, tolerance), expected, "{}", query);
+    }
+}
+
+#[test]
+fn display() {
+    let reason = Reason::Fuzzy {
+        entry: "thirteen".into(),
+        distance: 1,
+    };
+    assert_eq!(reason.to_string(), "1 edit(s) from entry \"thirteen\"");
+}
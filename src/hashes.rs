@@ -0,0 +1,116 @@
+//! Recognition of digests of thirteen strings. At build time, every seed in
+//! `data/hash_seeds.toml`, such as `"13"`, `"XIII"` and `"treize"`, is hashed with every
+//! [`Algorithm`]. [`identify`] then recognizes those digests written in hex, in any case, or in
+//! Base64, standard or URL-safe, with or without padding.
+//!
+//! The seed list can be replaced by setting the `IS_THIRTEEN_HASH_SEEDS` environment variable at
+//! build time to the path of another file in the same format.
+//!
+//! ```
+//! use is_thirteen::hashes::{identify, Algorithm, Digest, Encoding};
+//! use is_thirteen::IsThirteen;
+//!
+//! let found = identify("BD307A3EC329E10A2CFF8FB87480823DA114F8F4").unwrap();
+//! assert_eq!((found.seed, found.algorithm), ("13", Algorithm::Sha1));
+//!
+//! let found = identify("Qi7MCE8kWN78Yg7OvypkSA==").unwrap();
+//! assert_eq!(found.seed, "thirteen");
+//! assert_eq!(found.encoding, Encoding::Base64);
+//!
+//! assert!(Digest("3854745b").thirteen());
+//! assert!(!Digest("d41d8cd98f00b204e9800998ecf8427e").thirteen());
+//! ```
+
+use crate::decoder::decode_base64;
+use crate::IsThirteen;
+use phf::Map;
+use std::fmt;
+
+/// A hash algorithm. Integer digests are written big-endian, the way their hex forms usually are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    /// CRC-32 as used by zlib and `cksum -a crc32b`.
+    Crc32,
+    /// XXH32 with seed 0.
+    Xxh32,
+    /// XXH64 with seed 0.
+    Xxh64,
+    /// The 64-bit XXH3 with seed 0.
+    Xxh3,
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Algorithm::Md5 => "MD5",
+            Algorithm::Sha1 => "SHA-1",
+            Algorithm::Sha256 => "SHA-256",
+            Algorithm::Sha512 => "SHA-512",
+            Algorithm::Crc32 => "CRC-32",
+            Algorithm::Xxh32 => "XXH32",
+            Algorithm::Xxh64 => "XXH64",
+            Algorithm::Xxh3 => "XXH3-64",
+        })
+    }
+}
+
+/// How a digest is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Hex,
+    Base64,
+}
+
+/// What [`identify`] found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Identified {
+    /// The seed that was hashed.
+    pub seed: &'static str,
+    pub algorithm: Algorithm,
+    pub encoding: Encoding,
+}
+
+/// `Digest` is thirteen if [`identify`] recognizes it.
+#[derive(Debug, Clone, Copy)]
+pub struct Digest<'s>(pub &'s str);
+
+impl IsThirteen for Digest<'_> {
+    fn thirteen(&self) -> bool {
+        identify(self.0).is_some()
+    }
+}
+
+/// Returns the seed and algorithm of the digest `s`, ignoring surrounding whitespace, or `None`
+/// if it is not the digest of a seed. Strings of hex digits are read as hex first.
+pub fn identify(s: &str) -> Option<Identified> {
+    let s = s.trim();
+    let hex = if !s.is_empty() && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        DIGESTS.get(s.to_ascii_lowercase().as_str())
+    } else {
+        None
+    };
+    let (&(algorithm, seed), encoding) = match hex {
+        Some(found) => (found, Encoding::Hex),
+        None => {
+            let bytes = decode_base64(s)?;
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            (DIGESTS.get(hex.as_str())?, Encoding::Base64)
+        }
+    };
+    Some(Identified {
+        seed,
+        algorithm,
+        encoding,
+    })
+}
+
+// The digests of the seeds, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/hashes.rs"));
+
+#[cfg(test)]
+#[path = "hashes_test.rs"]
+mod hashes_test;
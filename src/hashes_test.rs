@@ -0,0 +1,80 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case("c51ce410c124a10e0db5e4b97fc2af39", "13", Algorithm::Md5)]
+#[case("C51CE410C124A10E0DB5E4B97FC2AF39", "13", Algorithm::Md5)]
+#[case("bd307a3ec329e10a2cff8fb87480823da114f8f4", "13", Algorithm::Sha1)]
+#[case(
+    "3fdba35f04dc8c462986c992bcf875546257113072a909c162f7e470e581e278",
+    "13",
+    Algorithm::Sha256
+)]
+#[case(
+    "da0438cfd8439a603d39f7b1d45545d3ce4cf741cb9ec8216cd1144d61c1eba0\
+     9165379411f879cbc9a7deaa81885316cb82703962d322f1fcc9c54b20ad5c55",
+    "thirteen",
+    Algorithm::Sha512
+)]
+#[case("3854745b", "13", Algorithm::Crc32)]
+#[case("a0b1ced0", "13", Algorithm::Xxh32)]
+#[case("22e84c6060359bac", "13", Algorithm::Xxh64)]
+#[case("90a0338accf546ca", "13", Algorithm::Xxh3)]
+#[case("5d74734a7d8cd3bba1140b9bbca21f5564d1c9be", "XIII", Algorithm::Sha1)]
+#[case("feee44ad365b6b1ec75c5621a0ad067371102854", "13\n", Algorithm::Sha1)]
+#[case("0195aeee", "treize", Algorithm::Crc32)]
+#[case("  c51ce410c124a10e0db5e4b97fc2af39\n", "13", Algorithm::Md5)]
+fn hex(#[case] s: &str, #[case] seed: &'static str, #[case] algorithm: Algorithm) {
+    assert_eq!(
+        identify(s),
+        Some(Identified {
+            seed,
+            algorithm,
+            encoding: Encoding::Hex,
+        })
+    );
+}
+
+#[rstest]
+#[case("xRzkEMEkoQ4NteS5f8KvOQ==", "13", Algorithm::Md5)]
+#[case("xRzkEMEkoQ4NteS5f8KvOQ", "13", Algorithm::Md5)]
+#[case("+MGbH1dmfmdDvHtdcQ+3AQ==", "XIII", Algorithm::Md5)]
+#[case("-MGbH1dmfmdDvHtdcQ-3AQ", "XIII", Algorithm::Md5)]
+#[case(
+    "GiUkApcvYFf6U8wXK1K5_8ppjhgxH6zQ87Buyq73nhc=",
+    "13\n",
+    Algorithm::Sha256
+)]
+fn base64(#[case] s: &str, #[case] seed: &str, #[case] algorithm: Algorithm) {
+    let found = identify(s).unwrap();
+    assert_eq!(found.seed, seed);
+    assert_eq!(found.algorithm, algorithm);
+    assert_eq!(found.encoding, Encoding::Base64);
+}
+
+#[rstest]
+#[case("")]
+#[case("13")]
+#[case("thirteen")]
+// MD5 of ""
+#[case("d41d8cd98f00b204e9800998ecf8427e")]
+// One digit short
+#[case("c51ce410c124a10e0db5e4b97fc2af3")]
+// Base64 with bits left over
+#[case("xRzkEMEkoQ4NteS5f8KvOR")]
+#[case("xRzk EMEkoQ4NteS5f8KvOQ==")]
+fn not_identified(#[case] s: &str) {
+    assert_eq!(identify(s), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Algorithm::Sha256.to_string(), "SHA-256");
+    assert_eq!(Algorithm::Xxh3.to_string(), "XXH3-64");
+}
+
+#[test]
+fn digest() {
+    assert!(Digest("xRzkEMEkoQ4NteS5f8KvOQ==").thirteen());
+    assert!(!Digest("fourteen").thirteen());
+}
@@ -0,0 +1,170 @@
+//! Decoding of leetspeak. [`decode`] tries every way to read the characters of a string with the
+//! substitutions in [`SUBSTITUTIONS`], so `"+h!r+33n"` and `"7H1R733N"` decode to `"thirteen"`
+//! although the dataset does not list them. Decodings are only checked against entries outside
+//! [`Categories::LEETSPEAK`] and `"13"`, so the hand-listed variants in that category are not
+//! needed to decode them.
+//!
+//! ```
+//! # #[cfg(all(feature = "numerals", feature = "languages"))] {
+//! use is_thirteen::leetspeak::{decode, Leetspeak};
+//! use is_thirteen::IsThirteen;
+//!
+//! assert_eq!(decode("+h!r+33n"), Some("thirteen"));
+//! assert_eq!(decode("|3"), Some("13"));
+//! assert_eq!(decode("7r3c3"), Some("trece"));
+//! assert!(Leetspeak("xiii").thirteen());
+//! assert!(!Leetspeak("f0urt33n").thirteen());
+//! # }
+//! ```
+
+use crate::thirteen_strings::{Categories, THIRTEEN_STRINGS};
+use crate::{explain, fold_case, IsThirteen, Reason};
+use once_cell::sync::OnceCell;
+use std::borrow::Cow;
+
+/// Leetspeak spellings and what each can stand for. Every character can also stand for itself.
+/// Matching is done on the lowercase string.
+pub const SUBSTITUTIONS: &[(&str, &[&str])] = &[
+    ("0", &["o"]),
+    ("1", &["i", "l"]),
+    ("2", &["z"]),
+    ("3", &["e"]),
+    ("4", &["a"]),
+    ("5", &["s"]),
+    ("6", &["g", "b"]),
+    ("7", &["t"]),
+    ("8", &["b"]),
+    ("9", &["g"]),
+    ("@", &["a"]),
+    ("$", &["s"]),
+    ("+", &["t"]),
+    ("€", &["e"]),
+    ("!", &["i", "1"]),
+    ("|", &["l", "i", "1"]),
+    // Letters that look like 1, as in "l3"
+    ("i", &["1"]),
+    ("l", &["1"]),
+    ("()", &["o"]),
+    ("|-|", &["h"]),
+    ("|\\|", &["n"]),
+    ("\\/", &["v"]),
+];
+
+/// `Leetspeak` is thirteen if it decodes to a thirteen string, or if it is thirteen as written.
+#[derive(Debug, Clone, Copy)]
+pub struct Leetspeak<'s>(pub &'s str);
+
+impl Leetspeak<'_> {
+    /// Returns [`Reason::Leetspeak`] with the decoded form if the string decodes to a thirteen
+    /// string, the same reason as [`explain`](crate::explain) if it is thirteen as written, or
+    /// `None`.
+    pub fn explain(&self) -> Option<Reason> {
+        decode(self.0)
+            .map(|decoded| Reason::Leetspeak(Cow::Borrowed(decoded)))
+            .or_else(|| explain(self.0))
+    }
+}
+
+impl IsThirteen for Leetspeak<'_> {
+    fn thirteen(&self) -> bool {
+        self.explain().is_some()
+    }
+}
+
+/// Returns the entry of [`THIRTEEN_STRINGS`], or `"13"`, that the lowercase version of `s` decodes
+/// to with at least one substitution. If there are several, the one in the earliest category is
+/// returned, with `"13"` first.
+pub fn decode(s: &str) -> Option<&'static str> {
+    let mut lowercase = String::new();
+    fold_case(s, &mut lowercase);
+    let mut search = Search {
+        targets: targets(),
+        decoded: String::with_capacity(lowercase.len()),
+        found: Vec::new(),
+    };
+    search.run(&lowercase, false);
+    search.found.into_iter().min_by_key(|&target| {
+        let rank = THIRTEEN_STRINGS
+            .category(target)
+            .map_or(0, |category| category.bits());
+        (rank, target)
+    })
+}
+
+/// The strings that decodings are checked against, sorted.
+fn targets() -> &'static [&'static str] {
+    static TARGETS: OnceCell<Vec<&'static str>> = OnceCell::new();
+    TARGETS.get_or_init(|| {
+        let mut targets: Vec<&'static str> = THIRTEEN_STRINGS
+            .iter()
+            .copied()
+            .filter(|&entry| THIRTEEN_STRINGS.category(entry) != Some(Categories::LEETSPEAK))
+            .collect();
+        targets.push("13");
+        targets.sort_unstable();
+        targets
+    })
+}
+
+/// A depth-first search over decodings that abandons a branch as soon as no target starts with
+/// the decoded prefix.
+struct Search {
+    targets: &'static [&'static str],
+    decoded: String,
+    found: Vec<&'static str>,
+}
+
+impl Search {
+    /// Decodes `rest` after `self.decoded`. `substituted` is whether `self.decoded` differs from
+    /// the input.
+    fn run(&mut self, rest: &str, substituted: bool) {
+        let first = match rest.chars().next() {
+            Some(first) => first,
+            None => {
+                let i = self.partition_point();
+                if substituted && self.targets.get(i) == Some(&self.decoded.as_str()) {
+                    self.found.push(self.targets[i]);
+                }
+                return;
+            }
+        };
+
+        let len = self.decoded.len();
+        self.decoded.push(first);
+        if self.is_prefix() {
+            self.run(&rest[first.len_utf8()..], substituted);
+        }
+        self.decoded.truncate(len);
+
+        for &(spelling, letters) in SUBSTITUTIONS {
+            if !rest.starts_with(spelling) {
+                continue;
+            }
+            for letter in letters {
+                self.decoded.push_str(letter);
+                if self.is_prefix() {
+                    self.run(&rest[spelling.len()..], true);
+                }
+                self.decoded.truncate(len);
+            }
+        }
+    }
+
+    /// Returns the index of the first target that is not less than `self.decoded`.
+    fn partition_point(&self) -> usize {
+        self.targets
+            .partition_point(|&target| target < self.decoded.as_str())
+    }
+
+    /// Returns `true` if a target starts with `self.decoded`.
+    fn is_prefix(&self) -> bool {
+        matches!(
+            self.targets.get(self.partition_point()),
+            Some(target) if target.starts_with(self.decoded.as_str())
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "leetspeak_test.rs"]
+mod leetspeak_test;
@@ -0,0 +1,60 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[cfg_attr(feature = "languages", case("+h!r+33n", Some("thirteen")))]
+#[cfg_attr(feature = "languages", case("7H1R733N", Some("thirteen")))]
+#[cfg_attr(feature = "languages", case("th|rt€€n", Some("thirteen")))]
+#[case("|-|", None)]
+#[cfg_attr(feature = "languages", case("7|-|1r733|\\|", Some("thirteen")))]
+#[cfg_attr(feature = "languages", case("7r3c3", Some("trece")))]
+#[cfg_attr(feature = "numerals", case("x!!!", Some("xiii")))]
+#[case("l3", Some("13"))]
+#[case("!3", Some("13"))]
+#[case("thirteen", None)]
+#[case("xiii", None)]
+#[case("f0urt33n", None)]
+#[case("", None)]
+fn decoding(#[case] s: &str, #[case] expected: Option<&str>) {
+    assert_eq!(decode(s), expected);
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn hand_listed_variants_decode() {
+    for entry in [
+        "th1rt33n", "th1rte3n", "th1rteen", "thirt3en", "thirt33n", "thirte3n", "7h1r733n",
+    ] {
+        assert_eq!(decode(entry), Some("thirteen"), "{}", entry);
+    }
+    for entry in ["i3", "l3", "|3", "!3"] {
+        assert_eq!(decode(entry), Some("13"), "{}", entry);
+    }
+}
+
+#[test]
+fn targets_are_not_leetspeak() {
+    let targets = targets();
+    assert!(targets.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(targets.contains(&"13"));
+    assert!(!targets.contains(&"th1rt33n"));
+}
+
+#[rstest]
+#[cfg_attr(feature = "languages", case("+h!r+33n", Some(Reason::Leetspeak("thirteen".into()))))]
+#[cfg_attr(feature = "languages", case("TH1RT33N", Some(Reason::Leetspeak("thirteen".into()))))]
+#[cfg_attr(feature = "numerals", case("XIII", Some(Reason::Entry("xiii".into()))))]
+#[cfg_attr(feature = "leetspeak", case("ß", Some(Reason::Entry("ß".into()))))]
+#[case("14", None)]
+fn wrapper(#[case] s: &str, #[case] expected: Option<Reason>) {
+    assert_eq!(Leetspeak(s).thirteen(), expected.is_some());
+    assert_eq!(Leetspeak(s).explain(), expected);
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Reason::Leetspeak("13".into()).to_string(),
+        "leetspeak for \"13\""
+    );
+}
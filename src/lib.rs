@@ -1,14 +1,99 @@
-#![doc = include_str!("../README.md")]
+// The README examples look up numerals and words in the dataset
+#![cfg_attr(
+    all(feature = "numerals", feature = "languages"),
+    doc = include_str!("../README.md")
+)]
 
-/// Contains all thirteen strings.
+/// Contains all thirteen strings, by category.
 pub mod thirteen_strings;
 
+/// Checks batches of strings.
+pub mod matcher;
+
+/// Checks numeric slices.
+pub mod batch;
+
+/// Thirteen strings that can be changed at runtime.
+pub mod registry;
+
+/// Finds thirteen literals in source code.
+pub mod scanner;
+
+/// Unicode normalization before lookup.
+pub mod normalizer;
+
+/// Matching of misspelled thirteen strings.
+pub mod fuzzy;
+
+/// Decoding of leetspeak.
+pub mod leetspeak;
+
+/// Detection of strings that look like thirteen strings.
+pub mod confusables;
+
+/// Morse code.
+pub mod morse;
+
+/// Detection of thirteen strings in classical ciphers.
+pub mod cipher;
+
+/// Decoding of encoded strings.
+pub mod decoder;
+
+/// Chinese numerals.
+pub mod cjk;
+
+/// Phonetic matching of thirteen strings.
+#[cfg(feature = "phonetic")]
+pub mod phonetic;
+
+/// Recognition of digests of thirteen strings.
+#[cfg(feature = "digests")]
+pub mod hashes;
+
+/// Language server that reports thirteens in open documents.
+#[cfg(feature = "lsp")]
+pub mod lsp;
+
+/// C API.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// Python bindings.
+#[cfg(feature = "python")]
+pub mod python;
+
+/// Checks for parallel iterators.
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+/// SQLite functions.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// JavaScript API for WebAssembly.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// HTTP service mode.
+#[cfg(feature = "server")]
+pub mod server;
+
+/// Apache Arrow kernels.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+/// Imports the original JavaScript library's data file and tests.
+#[cfg(feature = "upstream")]
+pub mod upstream;
+
 use fnv::FnvHashSet as HashSet;
 use num_traits::FromPrimitive;
 use once_cell::sync::OnceCell;
-use std::fmt::Debug;
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
 use std::ops::Rem;
-use thirteen_strings::THIRTEEN_STRINGS;
+use thirteen_strings::{ThirteenStrings, THIRTEEN_STRINGS};
 
 /// A type that can be compared to thirteen. This trait is implemented for all primitive types and
 /// `&str`.
@@ -55,20 +140,141 @@ macro_rules! impl_for_float {
 impl_for_float!(f64);
 impl_for_float!(f32);
 
+#[cfg(feature = "bigint")]
+impl IsThirteen for num_bigint::BigInt {
+    /// Returns `true` if `self == 13`.
+    fn thirteen(&self) -> bool {
+        *self == Self::from(13u8)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl IsThirteen for num_bigint::BigUint {
+    /// Returns `true` if `self == 13`.
+    fn thirteen(&self) -> bool {
+        *self == Self::from(13u8)
+    }
+}
+
+#[cfg(feature = "rational")]
+impl<T> IsThirteen for num_rational::Ratio<T>
+where
+    Self: PartialEq + FromPrimitive,
+{
+    /// Returns `true` if `self` is exactly 13, whether or not it is reduced, like `26/2`.
+    fn thirteen(&self) -> bool {
+        matches!(Self::from_u64(13), Some(thirteen) if *self == thirteen)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl IsThirteen for rust_decimal::Decimal {
+    /// Returns `true` if `self` is exactly 13, at any scale, like `13.000`.
+    fn thirteen(&self) -> bool {
+        *self == Self::from(13u8)
+    }
+}
+
 impl IsThirteen for &str {
     /// Returns `true` if:
     /// - `self` equals `"13"` or `"B"`
     /// - `self` is 13 characters long and all characters are equal to each other
     /// - The lowercase version of `self` is included in [`thirteen_strings::THIRTEEN_STRINGS`]
     fn thirteen(&self) -> bool {
-        matches!(*self, "13" | "B")
-            || (self.len() == 13 && self.bytes().all(|b| matches!(b, b'I' | b'l' | b'1')))
-            || is_thirteen_equal_chars(self)
-            // The next line could be non-allocating if there is an ascii-only IsThirteen
-            || THIRTEEN_STRINGS.contains(self.to_lowercase().as_str())
+        explain(self).is_some()
     }
 }
 
+/// The reason a string is thirteen, returned by [`explain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Reason {
+    /// The string is `"13"` or `"B"`.
+    Literal,
+    /// The string is 13 strokes made of `I`, `l` and `1`.
+    Strokes,
+    /// The string is the same character repeated 13 times.
+    RepeatedChar(char),
+    /// The lowercase string is this entry of [`thirteen_strings::THIRTEEN_STRINGS`] or of a
+    /// [`registry::ThirteenRegistry`].
+    Entry(Cow<'static, str>),
+    /// The string is `distance` edits away from this entry, and matched because of a
+    /// [`fuzzy::Tolerance`].
+    Fuzzy {
+        entry: Cow<'static, str>,
+        distance: usize,
+    },
+    /// The string sounds like this entry, as compared by `phonetic::SoundsLike`.
+    SoundsLike(Cow<'static, str>),
+    /// The string is leetspeak for this entry or for `"13"`, as decoded by
+    /// [`leetspeak::decode`].
+    Leetspeak(Cow<'static, str>),
+    /// The string looks like this entry, `"13"` or `"B"`, as compared by
+    /// [`confusables::LooksLike`].
+    LooksLike(Cow<'static, str>),
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Literal => f.write_str("literal"),
+            Reason::Strokes => f.write_str("13 strokes"),
+            Reason::RepeatedChar(c) => write!(f, "{:?} repeated 13 times", c),
+            Reason::Entry(entry) => write!(f, "entry {:?}", entry),
+            Reason::Fuzzy { entry, distance } => {
+                write!(f, "{} edit(s) from entry {:?}", distance, entry)
+            }
+            Reason::SoundsLike(entry) => write!(f, "sounds like entry {:?}", entry),
+            Reason::Leetspeak(decoded) => write!(f, "leetspeak for {:?}", decoded),
+            Reason::LooksLike(entry) => write!(f, "looks like {:?}", entry),
+        }
+    }
+}
+
+/// Returns why `s` is thirteen, or `None` if it is not. `s.thirteen()` is equivalent to
+/// `explain(s).is_some()`.
+pub fn explain(s: &str) -> Option<Reason> {
+    explain_with(s, &mut String::new(), fold_case, |lowercase| {
+        builtin_entry(&THIRTEEN_STRINGS, lowercase)
+    })
+}
+
+/// [`explain`], using `folded` as scratch space so that batches need not allocate per string,
+/// `fold` to write the form of `s` that dataset entries are stored in, and `lookup` to find that
+/// form in a dataset.
+fn explain_with<N, F>(s: &str, folded: &mut String, fold: N, lookup: F) -> Option<Reason>
+where
+    N: FnOnce(&str, &mut String),
+    F: FnOnce(&str) -> Option<Cow<'static, str>>,
+{
+    if matches!(s, "13" | "B") {
+        Some(Reason::Literal)
+    } else if s.len() == 13 && s.bytes().all(|b| matches!(b, b'I' | b'l' | b'1')) {
+        Some(Reason::Strokes)
+    } else if is_thirteen_equal_chars(s) {
+        s.chars().next().map(Reason::RepeatedChar)
+    } else {
+        fold(s, folded);
+        lookup(folded).map(Reason::Entry)
+    }
+}
+
+/// Writes the lowercase version of `s` to `lowercase`. Dataset entries are stored in this form.
+fn fold_case(s: &str, lowercase: &mut String) {
+    lowercase.clear();
+    if s.is_ascii() {
+        lowercase.extend(s.chars().map(|c| c.to_ascii_lowercase()));
+    } else {
+        lowercase.push_str(&s.to_lowercase());
+    }
+}
+
+fn builtin_entry(strings: &ThirteenStrings, lowercase: &str) -> Option<Cow<'static, str>> {
+    strings
+        .get_key(lowercase)
+        .map(|&entry| Cow::Borrowed(entry))
+}
+
 fn is_thirteen_equal_chars(s: &str) -> bool {
     if let Some(first_char) = s.chars().next() {
         if s.chars().count() == 13 {
@@ -138,11 +344,14 @@ pub struct DivisibleBy<T>(pub T);
 
 impl<T, RemOutput> IsThirteen for DivisibleBy<T>
 where
-    T: Rem<Output = RemOutput> + FromPrimitive + Copy,
+    T: Rem<Output = RemOutput> + FromPrimitive + Clone,
     RemOutput: PartialEq + FromPrimitive,
 {
     fn thirteen(&self) -> bool {
-        self.0 % FromPrimitive::from_u64(13).unwrap() == FromPrimitive::from_u64(0).unwrap()
+        match (T::from_u64(13), RemOutput::from_u64(0)) {
+            (Some(thirteen), Some(zero)) => self.0.clone() % thirteen == zero,
+            _ => false,
+        }
     }
 }
 
@@ -155,7 +364,7 @@ where
     T: PartialOrd + FromPrimitive,
 {
     fn thirteen(&self) -> bool {
-        self.0 > FromPrimitive::from_u64(13).unwrap()
+        matches!(T::from_u64(13), Some(thirteen) if self.0 > thirteen)
     }
 }
 
@@ -168,7 +377,7 @@ where
     T: PartialOrd + FromPrimitive,
 {
     fn thirteen(&self) -> bool {
-        self.0 < FromPrimitive::from_u64(13).unwrap()
+        matches!(T::from_u64(13), Some(thirteen) if self.0 < thirteen)
     }
 }
 
@@ -259,5 +468,132 @@ impl IsThirteen for AtomicNumber<'_> {
     }
 }
 
+/// `Base` is thirteen if it is a numeric string whose value is 13. Besides digits, it accepts:
+/// - A sign
+/// - A `0b`, `0o` or `0x` prefix that matches the radix
+/// - `_` and `'` between digits, as Rust, Python and C++ allow
+/// - An integer suffix like `u8` or `i64` if the value fits the type, or `u`, `l`, `ul`, `ll` or
+///   `ull` as in C, unless the suffix would be read as digits
+#[derive(Debug, Clone, Copy)]
+pub struct Base<'s> {
+    s: &'s str,
+    radix: Option<u32>,
+}
+
+impl<'s> Base<'s> {
+    /// Reads `s` in `radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in [2, 36], like `i64::from_str_radix`.
+    pub const fn new(s: &'s str, radix: u32) -> Self {
+        assert!(2 <= radix && radix <= 36, "radix must be in [2, 36]");
+        Self {
+            s,
+            radix: Some(radix),
+        }
+    }
+
+    /// Reads `s` in the radix of its prefix, or in decimal without one. Strings of letters only
+    /// are read as spreadsheet columns in bijective base 26, where `"A"` is 1 and `"M"` is 13.
+    pub const fn auto(s: &'s str) -> Self {
+        Self { s, radix: None }
+    }
+
+    /// Returns the value, or `None` if the string is not a number or does not fit in an `i128`.
+    pub fn value(&self) -> Option<i128> {
+        let s = self.s.trim();
+        if self.radix.is_none() && !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return s.bytes().try_fold(0i128, |column, b| {
+                let letter = b.to_ascii_uppercase() - b'A' + 1;
+                column.checked_mul(26)?.checked_add(i128::from(letter))
+            });
+        }
+
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let prefix = match s.get(..2).map(|prefix| prefix.to_ascii_lowercase()) {
+            Some(prefix) if prefix == "0b" => Some(2),
+            Some(prefix) if prefix == "0o" => Some(8),
+            Some(prefix) if prefix == "0x" => Some(16),
+            _ => None,
+        };
+        let (radix, s) = match (self.radix, prefix) {
+            (Some(radix), Some(prefix)) if radix == prefix => (radix, &s[2..]),
+            (None, Some(prefix)) => (prefix, &s[2..]),
+            (radix, _) => (radix.unwrap_or(10), s),
+        };
+        // A separator cannot come first unless it follows a prefix, as in `0x_d`
+        if prefix.is_none() && s.starts_with(['_', '\'']) {
+            return None;
+        }
+
+        let (digits, range) = split_integer_suffix(s, radix);
+        let mut value: i128 = 0;
+        let mut has_digit = false;
+        for c in digits.chars().filter(|&c| c != '_' && c != '\'') {
+            let digit = c.to_digit(radix)?;
+            value = value.checked_mul(i128::from(radix))?;
+            value = value.checked_add(i128::from(digit))?;
+            has_digit = true;
+        }
+        if !has_digit {
+            return None;
+        }
+        if negative {
+            value = -value;
+        }
+        match range {
+            Some((min, max)) if value < min || value > max => None,
+            _ => Some(value),
+        }
+    }
+}
+
+impl IsThirteen for Base<'_> {
+    fn thirteen(&self) -> bool {
+        self.value() == Some(13)
+    }
+}
+
+/// Splits an integer suffix off `s`, with the range of its type if it is a Rust suffix. Suffixes
+/// that start with a digit of `radix`, like `u8` in base 36, are left as digits.
+fn split_integer_suffix(s: &str, radix: u32) -> (&str, Option<(i128, i128)>) {
+    const RUST_SUFFIXES: &[(&str, i128, i128)] = &[
+        ("u128", 0, i128::MAX),
+        ("i128", i128::MIN, i128::MAX),
+        ("usize", 0, usize::MAX as i128),
+        ("isize", isize::MIN as i128, isize::MAX as i128),
+        ("u64", 0, u64::MAX as i128),
+        ("i64", i64::MIN as i128, i64::MAX as i128),
+        ("u32", 0, u32::MAX as i128),
+        ("i32", i32::MIN as i128, i32::MAX as i128),
+        ("u16", 0, u16::MAX as i128),
+        ("i16", i16::MIN as i128, i16::MAX as i128),
+        ("u8", 0, u8::MAX as i128),
+        ("i8", i8::MIN as i128, i8::MAX as i128),
+    ];
+    const C_SUFFIXES: &[&str] = &["ull", "llu", "ul", "lu", "ll", "u", "l"];
+
+    let is_digit = |suffix: &str| matches!(suffix.chars().next(), Some(c) if c.is_digit(radix));
+    for &(suffix, min, max) in RUST_SUFFIXES {
+        if let Some(digits) = s.strip_suffix(suffix) {
+            if !is_digit(suffix) {
+                return (digits, Some((min, max)));
+            }
+        }
+    }
+    let lowercase = s.to_ascii_lowercase();
+    for suffix in C_SUFFIXES {
+        if lowercase.ends_with(suffix) && !is_digit(suffix) {
+            return (&s[..s.len() - suffix.len()], None);
+        }
+    }
+    (s, None)
+}
+
 #[cfg(test)]
 mod lib_test;
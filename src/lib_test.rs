@@ -1,12 +1,10 @@
 use super::*;
-use chrono::Datelike;
-use chrono::TimeZone;
-use chrono::Utc;
 use rstest::rstest;
 
 #[rstest]
 // Tests from the is-thirteen suite
 // https://github.com/jezen/is-thirteen/blob/3e1cc843db584f7c8a9a13d8bc74a5e4bd1fa82f/test.js
+// Generated from upstream/test.js by `is-thirteen upstream tests`. Do not edit.
 #[case(13, true)] // 1
 #[case("13", true)] // 2
 #[case("۱۳", true)] // 3
@@ -36,7 +34,7 @@ use rstest::rstest;
 #[case("https://en.wikipedia.org/wiki/XIII_(video_game)", true)] // 24
 #[case("http://www.imdb.com/title/tt0798817/", true)] // 25
 #[case("https://www.imdb.com/title/tt2991516/", true)] // 26
-#[case(Utc.ymd(2021, 1, 1).year() - Utc.ymd(2003, 1, 1).year(), false)] // 27
+#[case(2021 - 2003, false)] // 27
 #[case("13+0i", true)] // 28
 #[case("13i", true)] // 29
 #[case("13 + 13i", true)] // 30
@@ -215,6 +213,26 @@ use rstest::rstest;
 #[case("bbbbbbbbbbb", false)] // 203
 #[case("||h||||||||||", false)] // 204
 #[case("///i/////////", false)] // 205
+// End of generated cases
+// The upstream suite expects every category of thirteen strings
+#[cfg(all(
+    feature = "numerals",
+    feature = "languages",
+    feature = "leetspeak",
+    feature = "encodings",
+    feature = "hashes",
+    feature = "pop-culture",
+    feature = "politics",
+    feature = "urls"
+))]
+fn upstream<T>(#[case] input: T, #[case] expected: bool)
+where
+    T: IsThirteen,
+{
+    assert_eq!(input.thirteen(), expected);
+}
+
+#[rstest]
 // Additional test cases
 #[case(0, false)] // 206
 #[case(13.0, true)] // 207
@@ -247,6 +265,11 @@ use rstest::rstest;
 #[case(LessThan(13), false)] // 234
 #[case(LessThan(12.0), true)] // 235
 #[case(Backwards("neetRihT"), true)] // 236
+#[case(Base::new("d", 16), true)] // 237
+#[case(Base::new("1101", 2), true)] // 238
+#[case(Base::new("13", 8), false)] // 239
+#[case(Base::auto("0o15"), true)] // 240
+#[case(Base::auto("M"), true)] // 241
 fn is_thirteen<T>(#[case] input: T, #[case] expected: bool)
 where
     T: IsThirteen,
@@ -262,6 +285,64 @@ fn test_is_thirteen_equal_chars(#[case] s: &str, #[case] expected: bool) {
     assert_eq!(is_thirteen_equal_chars(s), expected);
 }
 
+#[rstest]
+#[case("13", Some(Reason::Literal))]
+#[case("IlIlIlIlIlIlI", Some(Reason::Strokes))]
+#[case("aaaaaaaaaaaaa", Some(Reason::RepeatedChar('a')))]
+#[cfg_attr(feature = "languages", case("Thirteen", Some(Reason::Entry("thirteen".into()))))]
+#[cfg_attr(feature = "numerals", case("XIII", Some(Reason::Entry("xiii".into()))))]
+#[case("14", None)]
+fn test_explain(#[case] s: &str, #[case] expected: Option<Reason>) {
+    assert_eq!(explain(s), expected);
+}
+
+#[rstest]
+#[case(Base::new("D", 16), Some(13))]
+#[case(Base::new("0xD", 16), Some(13))]
+#[case(Base::new("0b1101", 2), Some(13))]
+#[case(Base::new("0x1101", 2), None)]
+#[case(Base::new("0b1101", 16), Some(0xb1101))]
+#[case(Base::new("d", 10), None)]
+#[case(Base::new("d", 36), Some(13))]
+#[case(Base::new("u8", 36), Some(30 * 36 + 8))]
+#[case(Base::new("-1_101", 2), Some(-13))]
+#[case(Base::new(" +15 ", 8), Some(13))]
+#[case(Base::auto("13"), Some(13))]
+#[case(Base::auto("0xd"), Some(13))]
+#[case(Base::auto("0X0D"), Some(13))]
+#[case(Base::auto("0b1101"), Some(13))]
+#[case(Base::auto("0x_d"), Some(13))]
+#[case(Base::auto("1_3"), Some(13))]
+#[case(Base::auto("1'3"), Some(13))]
+#[case(Base::auto("_13"), None)]
+#[case(Base::auto("13u8"), Some(13))]
+#[case(Base::auto("13_i64"), Some(13))]
+#[case(Base::auto("0xdusize"), Some(13))]
+#[case(Base::auto("300u8"), None)]
+#[case(Base::auto("-13u8"), None)]
+#[case(Base::auto("-13i8"), Some(-13))]
+#[case(Base::auto("13ULL"), Some(13))]
+#[case(Base::auto("0xdu"), Some(13))]
+#[case(Base::auto("M"), Some(13))]
+#[case(Base::auto("m"), Some(13))]
+#[case(Base::auto("AA"), Some(27))]
+#[case(Base::auto("XFD"), Some(16384))]
+#[case(Base::auto(""), None)]
+#[case(Base::auto("-"), None)]
+#[case(Base::auto("0x"), None)]
+#[case(Base::auto("u8"), None)]
+#[case(Base::auto("1 3"), None)]
+#[case(Base::auto("340282366920938463463374607431768211456"), None)]
+fn base_value(#[case] base: Base, #[case] expected: Option<i128>) {
+    assert_eq!(base.value(), expected);
+}
+
+#[test]
+#[should_panic(expected = "radix must be in [2, 36]")]
+fn base_radix() {
+    Base::new("13", 37);
+}
+
 #[test]
 fn thirteen_strings() {
     // All THIRTEEN_STRINGS should be lowercase
@@ -270,3 +351,47 @@ fn thirteen_strings() {
         .filter(|&&s| s != "B")
         .all(|&s| s == s.to_lowercase()));
 }
+
+#[cfg(feature = "bigint")]
+#[test]
+fn bigint() {
+    use num_bigint::{BigInt, BigUint};
+
+    assert!(BigInt::from(13).thirteen());
+    assert!(!BigInt::from(-13).thirteen());
+    assert!(BigUint::from(13u8).thirteen());
+    assert!(!(BigUint::from(13u8) << 64u32).thirteen());
+    assert!(DivisibleBy(BigInt::from(169)).thirteen());
+    assert!(GreaterThan(BigInt::from(u128::MAX)).thirteen());
+    assert!(LessThan(BigUint::from(12u8)).thirteen());
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn rational() {
+    use num_rational::Ratio;
+
+    assert!(Ratio::new(26, 2).thirteen());
+    assert!(Ratio::new_raw(26, 2).thirteen());
+    assert!(Ratio::new_raw(26u8, 2).thirteen());
+    assert!(!Ratio::new(131, 10).thirteen());
+    assert!(!Ratio::new(-26, -3).thirteen());
+    assert!(DivisibleBy(Ratio::new(52, 2)).thirteen());
+    assert!(!DivisibleBy(Ratio::new(13, 2)).thirteen());
+    assert!(GreaterThan(Ratio::new(131, 10)).thirteen());
+    assert!(LessThan(Ratio::new(129, 10)).thirteen());
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn decimal() {
+    use rust_decimal::Decimal;
+
+    assert!(Decimal::new(13000, 3).thirteen());
+    assert!(Decimal::from(13).thirteen());
+    assert!(!Decimal::new(130001, 4).thirteen());
+    assert!(DivisibleBy(Decimal::new(260, 1)).thirteen());
+    assert!(!DivisibleBy(Decimal::new(261, 1)).thirteen());
+    assert!(GreaterThan(Decimal::new(1301, 2)).thirteen());
+    assert!(!LessThan(Decimal::new(13000, 3)).thirteen());
+}
@@ -0,0 +1,319 @@
+use crate::scanner::{scan, Found, LiteralKind};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, Request as RequestTrait};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, NumberOrString, Position,
+    PublishDiagnosticsParams, Range, ServerCapabilities, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Url, WorkspaceEdit,
+};
+use std::collections::HashMap;
+use std::error::Error;
+
+/// Name of the constant that the "magic number" code action introduces.
+pub const CONSTANT_NAME: &str = "THIRTEEN";
+
+const SOURCE: &str = "is-thirteen";
+
+/// The language whose code actions declare [`CONSTANT_NAME`] when it is missing.
+const RUST: &str = "rust";
+
+/// An open document as the client last sent it.
+#[derive(Default)]
+struct Document {
+    language_id: String,
+    text: String,
+}
+
+/// Runs the language server over stdin and stdout until the client shuts it down.
+pub fn run_stdio() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let (connection, io_threads) = Connection::stdio();
+    run(&connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+/// Runs the language server on `connection` until the client shuts it down.
+///
+/// Every thirteen literal in an open document is reported as a diagnostic. Numbers get a "magic
+/// number" warning and a code action that replaces them with [`CONSTANT_NAME`]; strings get a
+/// hint and a code action that replaces them with `"13"`. In Rust documents the number action
+/// also declares the constant if it is missing.
+///
+/// Requests with malformed parameters get an `InvalidParams` error and malformed notifications
+/// are logged to stderr and skipped.
+pub fn run(connection: &Connection) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    let mut documents: HashMap<Url, Document> = HashMap::new();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                let response = handle_request(&documents, request);
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                let method = notification.method.clone();
+                match handle_notification(&mut documents, notification) {
+                    Ok(Some(uri)) => {
+                        let text = documents.get(&uri).map_or("", |document| &document.text);
+                        let params =
+                            PublishDiagnosticsParams::new(uri.clone(), diagnostics(text), None);
+                        connection
+                            .sender
+                            .send(Message::Notification(Notification::new(
+                                PublishDiagnostics::METHOD.to_string(),
+                                params,
+                            )))?;
+                    }
+                    Ok(None) => {}
+                    Err(e) => eprintln!("is-thirteen: skipping {} notification: {}", method, e),
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(documents: &HashMap<Url, Document>, request: Request) -> Response {
+    match request.method.as_str() {
+        CodeActionRequest::METHOD => {
+            match serde_json::from_value::<CodeActionParams>(request.params) {
+                Ok(params) => {
+                    let actions = match documents.get(&params.text_document.uri) {
+                        Some(document) => code_actions(
+                            &params.text_document.uri,
+                            document.language_id == RUST,
+                            &document.text,
+                            params.range,
+                        ),
+                        None => Vec::new(),
+                    };
+                    Response::new_ok(request.id, actions)
+                }
+                Err(e) => {
+                    Response::new_err(request.id, ErrorCode::InvalidParams as i32, e.to_string())
+                }
+            }
+        }
+        _ => Response::new_err(
+            request.id,
+            ErrorCode::MethodNotFound as i32,
+            format!("unsupported request: {}", request.method),
+        ),
+    }
+}
+
+/// Updates `documents` and returns the URI of the document whose diagnostics should be
+/// republished.
+fn handle_notification(
+    documents: &mut HashMap<Url, Document>,
+    notification: Notification,
+) -> Result<Option<Url>, serde_json::Error> {
+    Ok(match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+            documents.insert(
+                params.text_document.uri.clone(),
+                Document {
+                    language_id: params.text_document.language_id,
+                    text: params.text_document.text,
+                },
+            );
+            Some(params.text_document.uri)
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+            // Full sync, so the last change contains the whole document
+            match params.content_changes.into_iter().last() {
+                Some(change) => {
+                    documents
+                        .entry(params.text_document.uri.clone())
+                        .or_default()
+                        .text = change.text;
+                    Some(params.text_document.uri)
+                }
+                None => None,
+            }
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+            documents.remove(&params.text_document.uri);
+            Some(params.text_document.uri)
+        }
+        _ => None,
+    })
+}
+
+fn diagnostics(text: &str) -> Vec<Diagnostic> {
+    let lines = LineIndex::new(text);
+    scan(text)
+        .filter(|found| !declares_constant(text, found))
+        .map(|found| {
+            let (severity, code, message) = match found.kind {
+                LiteralKind::Number => (
+                    DiagnosticSeverity::WARNING,
+                    "magic-thirteen",
+                    "Magic number 13",
+                ),
+                LiteralKind::String => (
+                    DiagnosticSeverity::HINT,
+                    "thirteen-string",
+                    "This string is thirteen",
+                ),
+            };
+            Diagnostic {
+                range: lines.range(&found),
+                severity: Some(severity),
+                code: Some(NumberOrString::String(code.to_string())),
+                source: Some(SOURCE.to_string()),
+                message: message.to_string(),
+                ..Diagnostic::default()
+            }
+        })
+        .collect()
+}
+
+/// Returns the quick fixes for the literals in `range`. With `declare`, replacing a number also
+/// declares [`CONSTANT_NAME`] as a Rust `const` if `text` does not declare it yet.
+fn code_actions(uri: &Url, declare: bool, text: &str, range: Range) -> Vec<CodeActionOrCommand> {
+    let lines = LineIndex::new(text);
+    let declare = declare && !scan(text).any(|found| declares_constant(text, &found));
+    scan(text)
+        .filter(|found| !declares_constant(text, found))
+        .map(|found| (lines.range(&found), found))
+        .filter(|(found_range, _)| found_range.start <= range.end && range.start <= found_range.end)
+        .map(|(found_range, found)| {
+            let literal = &text[found.range.clone()];
+            let (title, new_text) = match found.kind {
+                LiteralKind::Number => (
+                    format!("Replace with named constant `{}`", CONSTANT_NAME),
+                    CONSTANT_NAME.to_string(),
+                ),
+                LiteralKind::String => {
+                    let quote = &literal[..1];
+                    (
+                        format!("Replace with {}13{}", quote, quote),
+                        format!("{}13{}", quote, quote),
+                    )
+                }
+            };
+            let mut edits = vec![TextEdit::new(found_range, new_text)];
+            if found.kind == LiteralKind::Number && declare {
+                let position = lines.position(declaration_offset(text));
+                edits.insert(
+                    0,
+                    TextEdit::new(
+                        Range::new(position, position),
+                        format!(
+                            "const {}: {} = 13;\n",
+                            CONSTANT_NAME,
+                            constant_type(literal)
+                        ),
+                    ),
+                );
+            }
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(WorkspaceEdit::new(HashMap::from([(uri.clone(), edits)]))),
+                ..CodeAction::default()
+            })
+        })
+        .collect()
+}
+
+/// Returns `true` if `found` is the value of a `THIRTEEN` constant, as in
+/// `pub const THIRTEEN: u8 = 13;`. That literal is neither reported nor replaced.
+fn declares_constant(text: &str, found: &Found) -> bool {
+    let line_start = text[..found.range.start].rfind('\n').map_or(0, |i| i + 1);
+    let declaration = text[line_start..found.range.start]
+        .trim_end()
+        .strip_suffix('=')
+        .and_then(|before| before.split_once(':'));
+    match declaration {
+        Some((name, _)) => {
+            let mut words = name.split_whitespace().rev();
+            words.next() == Some(CONSTANT_NAME) && words.next() == Some("const")
+        }
+        None => false,
+    }
+}
+
+/// Returns where the code action declares the constant: the start of the first line that is not
+/// a shebang, an inner attribute or an inner doc comment.
+fn declaration_offset(text: &str) -> usize {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if !line.starts_with("#!") && !line.starts_with("//!") {
+            break;
+        }
+        offset += line.len();
+    }
+    offset
+}
+
+/// Returns the Rust type of the constant that replaces the number `literal`: its type suffix, or
+/// the type Rust gives it without one.
+fn constant_type(literal: &str) -> &str {
+    const SUFFIXES: [&str; 14] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64",
+    ];
+    let hex = literal.starts_with("0x") || literal.starts_with("0X");
+    match SUFFIXES.iter().find(|suffix| literal.ends_with(*suffix)) {
+        Some(suffix) if !hex => suffix,
+        _ if !hex && literal.contains(['.', 'e', 'E']) => "f64",
+        _ => "i32",
+    }
+}
+
+/// Converts byte offsets to LSP positions, which count UTF-16 code units.
+struct LineIndex<'t> {
+    text: &'t str,
+    line_starts: Vec<usize>,
+}
+
+impl<'t> LineIndex<'t> {
+    fn new(text: &'t str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = self.text[line_start..offset]
+            .chars()
+            .map(char::len_utf16)
+            .sum::<usize>();
+        Position::new(line as u32, character as u32)
+    }
+
+    fn range(&self, found: &Found) -> Range {
+        Range::new(
+            self.position(found.range.start),
+            self.position(found.range.end),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "lsp_test.rs"]
+mod lsp_test;
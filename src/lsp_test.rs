@@ -0,0 +1,310 @@
+use super::*;
+use lsp_server::RequestId;
+use lsp_types::request::{Initialize, Shutdown};
+use lsp_types::{
+    InitializeParams, PartialResultParams, TextDocumentContentChangeEvent, TextDocumentIdentifier,
+    TextDocumentItem, VersionedTextDocumentIdentifier, WorkDoneProgressParams,
+};
+use rstest::rstest;
+use std::thread;
+
+#[rstest]
+#[case("", 0, Position::new(0, 0))]
+#[case("ab\ncd", 1, Position::new(0, 1))]
+#[case("ab\ncd", 3, Position::new(1, 0))]
+#[case("ab\ncd", 5, Position::new(1, 2))]
+#[case("十三\n13", 6, Position::new(0, 2))]
+#[case("🐱13", 4, Position::new(0, 2))]
+fn test_position(#[case] text: &str, #[case] offset: usize, #[case] expected: Position) {
+    assert_eq!(LineIndex::new(text).position(offset), expected);
+}
+
+#[test]
+fn test_diagnostics() {
+    let diagnostics = diagnostics("let a = 13;\nlet b = \"xiii\";\nlet c = 14;");
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(
+        diagnostics[0].range,
+        Range::new(Position::new(0, 8), Position::new(0, 10))
+    );
+    assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::WARNING));
+    assert_eq!(
+        diagnostics[1].range,
+        Range::new(Position::new(1, 8), Position::new(1, 14))
+    );
+    assert_eq!(diagnostics[1].severity, Some(DiagnosticSeverity::HINT));
+}
+
+fn uri() -> Url {
+    Url::parse("file:///thirteen.rs").unwrap()
+}
+
+/// Returns the edit that replaces the literal, which comes after the constant's declaration.
+fn replacement(action: &CodeActionOrCommand) -> &TextEdit {
+    match action {
+        CodeActionOrCommand::CodeAction(action) => {
+            action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri()]
+                .last()
+                .unwrap()
+        }
+        CodeActionOrCommand::Command(_) => panic!("expected a code action"),
+    }
+}
+
+#[rstest]
+#[case(Position::new(0, 0), Position::new(0, 0), vec![])]
+#[case(Position::new(0, 9), Position::new(0, 9), vec![(8, 10, "THIRTEEN")])]
+#[case(Position::new(1, 9), Position::new(1, 9), vec![(8, 16, "'13'")])]
+#[case(Position::new(0, 0), Position::new(2, 0), vec![(8, 10, "THIRTEEN"), (8, 16, "'13'")])]
+fn test_code_actions(
+    #[case] start: Position,
+    #[case] end: Position,
+    #[case] expected: Vec<(u32, u32, &str)>,
+) {
+    let text = "let a = 13;\nlet b = 'treize';\nlet c = 'tretton';";
+    let actions = code_actions(&uri(), true, text, Range::new(start, end));
+    let edits: Vec<(u32, u32, &str)> = actions
+        .iter()
+        .map(replacement)
+        .map(|edit| {
+            (
+                edit.range.start.character,
+                edit.range.end.character,
+                edit.new_text.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(edits, expected);
+}
+
+#[rstest]
+#[case(
+    "let a = 13u8;",
+    true,
+    Position::new(0, 8),
+    vec![
+        TextEdit::new(
+            Range::new(Position::new(0, 0), Position::new(0, 0)),
+            "const THIRTEEN: u8 = 13;\n".to_string(),
+        ),
+        TextEdit::new(
+            Range::new(Position::new(0, 8), Position::new(0, 12)),
+            "THIRTEEN".to_string(),
+        ),
+    ]
+)]
+#[case(
+    "//! Docs\n#![no_std]\nfn f() -> f64 { 13.0 }",
+    true,
+    Position::new(2, 17),
+    vec![
+        TextEdit::new(
+            Range::new(Position::new(2, 0), Position::new(2, 0)),
+            "const THIRTEEN: f64 = 13;\n".to_string(),
+        ),
+        TextEdit::new(
+            Range::new(Position::new(2, 16), Position::new(2, 20)),
+            "THIRTEEN".to_string(),
+        ),
+    ]
+)]
+#[case(
+    "pub const THIRTEEN: i32 = 13;\nlet a = 0xd;",
+    true,
+    Position::new(1, 8),
+    vec![TextEdit::new(
+        Range::new(Position::new(1, 8), Position::new(1, 11)),
+        "THIRTEEN".to_string(),
+    )]
+)]
+#[case(
+    "let a = 13;",
+    false,
+    Position::new(0, 8),
+    vec![TextEdit::new(
+        Range::new(Position::new(0, 8), Position::new(0, 10)),
+        "THIRTEEN".to_string(),
+    )]
+)]
+fn test_code_action_edit(
+    #[case] text: &str,
+    #[case] declare: bool,
+    #[case] position: Position,
+    #[case] expected: Vec<TextEdit>,
+) {
+    let actions = code_actions(&uri(), declare, text, Range::new(position, position));
+    assert_eq!(
+        actions,
+        [CodeActionOrCommand::CodeAction(CodeAction {
+            title: "Replace with named constant `THIRTEEN`".to_string(),
+            kind: Some(CodeActionKind::QUICKFIX),
+            edit: Some(WorkspaceEdit::new(HashMap::from([(uri(), expected)]))),
+            ..CodeAction::default()
+        })]
+    );
+}
+
+#[rstest]
+#[case("const THIRTEEN: i32 = 13;", false)]
+#[case("pub(crate) const THIRTEEN: u8 = 0xd;", false)]
+#[case("const NOT_THIRTEEN: i32 = 13;", true)]
+#[case("let THIRTEEN: i32 = 13;", true)]
+fn test_constant_not_reported(#[case] text: &str, #[case] reported: bool) {
+    assert_eq!(!diagnostics(text).is_empty(), reported);
+}
+
+#[rstest]
+#[case("13", "i32")]
+#[case("0xd", "i32")]
+#[case("13usize", "usize")]
+#[case("13_i64", "i64")]
+#[case("13.0", "f64")]
+#[case("1.3e1", "f64")]
+#[case("13f32", "f32")]
+fn test_constant_type(#[case] literal: &str, #[case] expected: &str) {
+    assert_eq!(constant_type(literal), expected);
+}
+
+fn request<R: RequestTrait>(id: i32, params: R::Params) -> Message {
+    Message::Request(Request::new(
+        RequestId::from(id),
+        R::METHOD.to_string(),
+        params,
+    ))
+}
+
+fn notification<N: NotificationTrait>(params: N::Params) -> Message {
+    Message::Notification(Notification::new(N::METHOD.to_string(), params))
+}
+
+fn published_diagnostics(client: &Connection) -> Vec<Diagnostic> {
+    match client.receiver.recv().unwrap() {
+        Message::Notification(notification) => {
+            assert_eq!(notification.method, PublishDiagnostics::METHOD);
+            serde_json::from_value::<PublishDiagnosticsParams>(notification.params)
+                .unwrap()
+                .diagnostics
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+}
+
+#[test]
+fn session() {
+    let (server, client) = Connection::memory();
+    let handle = thread::spawn(move || run(&server).unwrap());
+
+    client
+        .sender
+        .send(request::<Initialize>(1, InitializeParams::default()))
+        .unwrap();
+    assert!(matches!(
+        client.receiver.recv().unwrap(),
+        Message::Response(_)
+    ));
+    client
+        .sender
+        .send(notification::<lsp_types::notification::Initialized>(
+            lsp_types::InitializedParams {},
+        ))
+        .unwrap();
+
+    client
+        .sender
+        .send(notification::<DidOpenTextDocument>(
+            DidOpenTextDocumentParams {
+                text_document: TextDocumentItem::new(
+                    uri(),
+                    "rust".to_string(),
+                    1,
+                    "13".to_string(),
+                ),
+            },
+        ))
+        .unwrap();
+    assert_eq!(published_diagnostics(&client).len(), 1);
+
+    client
+        .sender
+        .send(notification::<DidChangeTextDocument>(
+            DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier::new(uri(), 2),
+                content_changes: vec![TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text: "13 + \"13\"".to_string(),
+                }],
+            },
+        ))
+        .unwrap();
+    assert_eq!(published_diagnostics(&client).len(), 2);
+
+    client
+        .sender
+        .send(request::<CodeActionRequest>(
+            2,
+            CodeActionParams {
+                text_document: TextDocumentIdentifier::new(uri()),
+                range: Range::new(Position::new(0, 0), Position::new(0, 1)),
+                context: Default::default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        ))
+        .unwrap();
+    match client.receiver.recv().unwrap() {
+        Message::Response(response) => {
+            let actions: Vec<CodeActionOrCommand> =
+                serde_json::from_value(response.result.unwrap()).unwrap();
+            assert_eq!(actions.len(), 1);
+            assert_eq!(replacement(&actions[0]).new_text, CONSTANT_NAME);
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+
+    client
+        .sender
+        .send(Message::Request(Request::new(
+            RequestId::from(3),
+            CodeActionRequest::METHOD.to_string(),
+            "not params",
+        )))
+        .unwrap();
+    match client.receiver.recv().unwrap() {
+        Message::Response(response) => {
+            assert_eq!(
+                response.error.unwrap().code,
+                ErrorCode::InvalidParams as i32
+            );
+        }
+        message => panic!("unexpected message: {:?}", message),
+    }
+    client
+        .sender
+        .send(Message::Notification(Notification::new(
+            DidOpenTextDocument::METHOD.to_string(),
+            "not params",
+        )))
+        .unwrap();
+
+    client
+        .sender
+        .send(notification::<DidCloseTextDocument>(
+            DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier::new(uri()),
+            },
+        ))
+        .unwrap();
+    assert!(published_diagnostics(&client).is_empty());
+
+    client.sender.send(request::<Shutdown>(4, ())).unwrap();
+    assert!(matches!(
+        client.receiver.recv().unwrap(),
+        Message::Response(_)
+    ));
+    client
+        .sender
+        .send(notification::<lsp_types::notification::Exit>(()))
+        .unwrap();
+    handle.join().unwrap();
+}
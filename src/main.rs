@@ -1,8 +1,50 @@
 use is_thirteen::IsThirteen;
-use std::io::{stdin, Read};
+use std::io::{stdin, stdout, BufRead, BufWriter, Read, Write};
+
+/// Lines read from stdin at a time in `--lines` mode.
+const BATCH: usize = 64 * 1024;
+
+const USAGE: &str = "\
+Usage:
+    is-thirteen                         Reads stdin and prints whether it is thirteen
+    is-thirteen --lines [--jobs N]      Prints whether each line of stdin is thirteen, using N
+                                        threads (feature \"rayon\", 0 uses every core)
+    is-thirteen serve [--bind ADDR]     Serves thirteen checks over HTTP (feature \"server\")
+    is-thirteen lsp                     Runs a language server over stdio (feature \"lsp\")
+    is-thirteen upstream consts CONSTS_JS [DATA_TOML]
+                                        Compares upstream's consts.js to the built-in strings and
+                                        prints a patch for DATA_TOML (feature \"upstream\")
+    is-thirteen upstream tests TEST_JS LIB_TEST_RS [STRING_CASES_RS [CHAIN_CASES_RS]]
+                                        Regenerates the upstream cases in LIB_TEST_RS, and the
+                                        string and chain cases for the wasm tests in
+                                        STRING_CASES_RS and CHAIN_CASES_RS, from upstream's
+                                        test.js (feature \"upstream\")";
 
 /// Reads from stdin and outputs `true` if it is a thirteen string or `false` otherwise.
-fn main() -> Result<(), std::io::Error> {
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => check_stdin(),
+        Some("--lines") => check_lines(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        Some("lsp") => lsp(),
+        Some("upstream") => upstream(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(arg) => Err(format!("unexpected argument: {}\n\n{}", arg, USAGE).into()),
+    }
+}
+
+fn check_stdin() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let mut input = String::new();
     stdin().read_to_string(&mut input)?;
     if input.ends_with('\n') {
@@ -12,3 +54,150 @@ fn main() -> Result<(), std::io::Error> {
     println!("{}", input.thirteen());
     Ok(())
 }
+
+fn check_lines(args: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let jobs = match flag_value(args, "--jobs")? {
+        Some(jobs) => jobs
+            .parse()
+            .map_err(|_| format!("invalid value for --jobs: {}", jobs))?,
+        None => 1,
+    };
+    let mask = line_mask(jobs)?;
+
+    let stdin = stdin();
+    let mut lines = stdin.lock().lines();
+    let stdout = stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    loop {
+        let batch = lines.by_ref().take(BATCH).collect::<Result<Vec<_>, _>>()?;
+        if batch.is_empty() {
+            break;
+        }
+        for thirteen in mask(&batch) {
+            writeln!(output, "{}", thirteen)?;
+        }
+    }
+    output.flush()?;
+    Ok(())
+}
+
+type LineMask = Box<dyn Fn(&[String]) -> Vec<bool>>;
+
+/// Returns a function that checks a batch of lines on `jobs` threads.
+#[cfg(feature = "rayon")]
+fn line_mask(jobs: usize) -> Result<LineMask, Box<dyn std::error::Error + Send + Sync>> {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    Ok(Box::new(move |lines| {
+        pool.install(|| is_thirteen::parallel::par_mask(lines))
+    }))
+}
+
+#[cfg(not(feature = "rayon"))]
+fn line_mask(jobs: usize) -> Result<LineMask, Box<dyn std::error::Error + Send + Sync>> {
+    if jobs != 1 {
+        return Err("--jobs requires is-thirteen to be built with the \"rayon\" feature".into());
+    }
+    let matcher = is_thirteen::matcher::Matcher::new();
+    Ok(Box::new(move |lines| {
+        matcher.mask(lines.iter().map(String::as_str))
+    }))
+}
+
+/// Returns the value following `flag` in `args`.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Result<Option<&'a str>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) => match args.get(i + 1) {
+            Some(value) => Ok(Some(value)),
+            None => Err(format!("{} requires a value", flag)),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(feature = "server")]
+fn serve(args: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use is_thirteen::server::{Server, DEFAULT_BIND};
+
+    let bind = flag_value(args, "--bind")?.unwrap_or(DEFAULT_BIND);
+    let server = Server::bind(bind)?;
+    eprintln!("Listening on http://{}", bind);
+    server.run();
+    Ok(())
+}
+
+#[cfg(not(feature = "server"))]
+fn serve(_: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    Err("is-thirteen was built without the \"server\" feature".into())
+}
+
+#[cfg(feature = "lsp")]
+fn lsp() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    is_thirteen::lsp::run_stdio()
+}
+
+#[cfg(not(feature = "lsp"))]
+fn lsp() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    Err("is-thirteen was built without the \"lsp\" feature".into())
+}
+
+#[cfg(feature = "upstream")]
+fn upstream(args: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use is_thirteen::thirteen_strings::THIRTEEN_STRINGS;
+    use is_thirteen::upstream;
+    use std::fs::{read_to_string, write};
+
+    let read = |path: &str| read_to_string(path).map_err(|e| format!("{}: {}", path, e));
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["consts", consts_js, rest @ ..] if rest.len() <= 1 => {
+            let data_toml = rest
+                .first()
+                .copied()
+                .unwrap_or("data/thirteen_strings.toml");
+            let entries = upstream::parse_consts(&read(consts_js)?)
+                .map_err(|e| format!("{}: {}", consts_js, e))?;
+            let comparison = upstream::compare(&entries, &THIRTEEN_STRINGS);
+            eprintln!(
+                "{} new, {} missing, {} differently cased",
+                comparison.new.len(),
+                comparison.missing.len(),
+                comparison.recased.len()
+            );
+            for entry in &comparison.new {
+                eprintln!("new: {:?}", entry);
+            }
+            for entry in &comparison.missing {
+                eprintln!("missing: {:?}", entry);
+            }
+            for (entry, builtin) in &comparison.recased {
+                eprintln!("differently cased: {:?} is {:?}", entry, builtin);
+            }
+            print!("{}", comparison.patch(data_toml, &read(data_toml)?));
+            Ok(())
+        }
+        ["tests", test_js, lib_test_rs, rest @ ..] if rest.len() <= 2 => {
+            let string_cases_rs = rest.first().copied().unwrap_or("upstream/string_cases.rs");
+            let chain_cases_rs = rest.get(1).copied().unwrap_or("upstream/chain_cases.rs");
+            let cases = upstream::parse_tests(&read(test_js)?)
+                .map_err(|e| format!("{}: {}", test_js, e))?;
+            let lib_test =
+                upstream::replace_generated(&read(lib_test_rs)?, &upstream::generate_cases(&cases))
+                    .map_err(|e| format!("{}: {}", lib_test_rs, e))?;
+            write(lib_test_rs, lib_test).map_err(|e| format!("{}: {}", lib_test_rs, e))?;
+            eprintln!("Wrote {} cases to {}", cases.len(), lib_test_rs);
+            write(string_cases_rs, upstream::generate_string_cases(&cases))
+                .map_err(|e| format!("{}: {}", string_cases_rs, e))?;
+            eprintln!("Wrote the string cases to {}", string_cases_rs);
+            write(chain_cases_rs, upstream::generate_chain_cases(&cases))
+                .map_err(|e| format!("{}: {}", chain_cases_rs, e))?;
+            eprintln!("Wrote the chain cases to {}", chain_cases_rs);
+            Ok(())
+        }
+        _ => Err(format!("unexpected arguments to upstream\n\n{}", USAGE).into()),
+    }
+}
+
+#[cfg(not(feature = "upstream"))]
+fn upstream(_: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    Err("is-thirteen was built without the \"upstream\" feature".into())
+}
@@ -0,0 +1,205 @@
+use crate::fuzzy::{BkTree, Tolerance};
+use crate::normalizer::Normalizer;
+use crate::registry::ThirteenRegistry;
+use crate::thirteen_strings::{Categories, ThirteenStrings};
+use crate::{builtin_entry, explain_with, fold_case, Reason};
+use fnv::FnvHashMap as HashMap;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Gives the same verdicts as `&str::thirteen`, but checks batches of strings without allocating
+/// for each one. A matcher built from a [`ThirteenRegistry`] looks strings up in the registry
+/// instead of [`THIRTEEN_STRINGS`](crate::thirteen_strings::THIRTEEN_STRINGS).
+///
+/// ```
+/// # #[cfg(feature = "numerals")] {
+/// use is_thirteen::matcher::Matcher;
+/// use is_thirteen::thirteen_strings::Categories;
+///
+/// let matcher = Matcher::new();
+/// assert_eq!(matcher.mask(["13", "XIII", "14"]), [true, true, false]);
+///
+/// let matcher = Matcher::with_categories(Categories::all() - Categories::POLITICS);
+/// assert_eq!(matcher.mask(["Dilma", "XIII"]), [false, true]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Matcher {
+    /// `None` uses `builtin`.
+    registry: Option<Arc<ThirteenRegistry>>,
+    builtin: ThirteenStrings,
+    /// `None` looks up lowercase strings directly.
+    normalized: Option<Arc<NormalizedIndex>>,
+    /// `None` only accepts exact matches.
+    fuzzy: Option<(Tolerance, Arc<BkTree>)>,
+}
+
+/// The dataset of a matcher, keyed by the normalized versions of its entries.
+#[derive(Debug)]
+struct NormalizedIndex {
+    normalizer: Normalizer,
+    entries: HashMap<String, Cow<'static, str>>,
+}
+
+impl Matcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a matcher that only looks up built-in strings in `categories`.
+    pub fn with_categories(categories: Categories) -> Self {
+        Self {
+            builtin: ThirteenStrings::new(categories),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a matcher that normalizes strings with `normalizer` before looking them up.
+    /// [`Reason::Entry`] still holds the entry as it is stored. Entries that normalize to the same
+    /// string are merged, and the smallest one is reported.
+    pub fn with_normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalized = if normalizer == Normalizer::new() {
+            None
+        } else {
+            let mut index = HashMap::default();
+            for entry in self.dataset() {
+                index.entry(normalizer.normalized(&entry)).or_insert(entry);
+            }
+            Some(Arc::new(NormalizedIndex {
+                normalizer,
+                entries: index,
+            }))
+        };
+        if let Some((tolerance, _)) = self.fuzzy {
+            self = self.with_fuzzy(tolerance);
+        }
+        self
+    }
+
+    /// Returns a matcher that also accepts strings within `tolerance` of an entry, after they are
+    /// normalized if the matcher has a [`Normalizer`]. [`Matcher::explain`] returns
+    /// [`Reason::Fuzzy`] for them.
+    pub fn with_fuzzy(mut self, tolerance: Tolerance) -> Self {
+        let tree = match &self.normalized {
+            Some(index) => index
+                .entries
+                .iter()
+                .map(|(key, entry)| (Cow::Owned(key.clone()), entry.clone()))
+                .collect(),
+            None => self
+                .dataset()
+                .into_iter()
+                .map(|entry| (entry.clone(), entry))
+                .collect(),
+        };
+        self.fuzzy = Some((tolerance, Arc::new(tree)));
+        self
+    }
+
+    /// Returns `true` if `s` is thirteen.
+    pub fn is_match(&self, s: &str) -> bool {
+        self.explain(s).is_some()
+    }
+
+    /// Returns why `s` is thirteen, or `None` if it is not.
+    pub fn explain(&self, s: &str) -> Option<Reason> {
+        self.explain_with_buffer(s, &mut String::new())
+    }
+
+    /// Returns whether each string in `strings` is thirteen.
+    pub fn mask<'a, I>(&self, strings: I) -> Vec<bool>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut buffer = String::new();
+        strings
+            .into_iter()
+            .map(|s| self.explain_with_buffer(s, &mut buffer).is_some())
+            .collect()
+    }
+
+    /// Returns the number of thirteens in `strings`.
+    pub fn count<'a, I>(&self, strings: I) -> usize
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut buffer = String::new();
+        strings
+            .into_iter()
+            .filter(|s| self.explain_with_buffer(s, &mut buffer).is_some())
+            .count()
+    }
+
+    /// Returns the entries of the dataset in sorted order.
+    pub fn entries(&self) -> Vec<&str> {
+        let mut entries: Vec<&str> = match &self.registry {
+            Some(registry) => registry.iter().collect(),
+            None => self.builtin.iter().copied().collect(),
+        };
+        entries.sort_unstable();
+        entries
+    }
+
+    /// Returns the entries of the dataset, sorted.
+    fn dataset(&self) -> Vec<Cow<'static, str>> {
+        let mut entries: Vec<Cow<'static, str>> = match &self.registry {
+            Some(registry) => registry
+                .iter()
+                .filter_map(|entry| registry.get(entry))
+                .collect(),
+            None => self
+                .builtin
+                .iter()
+                .map(|&entry| Cow::Borrowed(entry))
+                .collect(),
+        };
+        entries.sort_unstable();
+        entries
+    }
+
+    fn explain_with_buffer(&self, s: &str, buffer: &mut String) -> Option<Reason> {
+        let reason = self.explain_exact(s, buffer);
+        match &self.fuzzy {
+            // `buffer` holds the folded string if no rule matched
+            Some((tolerance, tree)) if reason.is_none() => tree.explain(buffer, *tolerance),
+            _ => reason,
+        }
+    }
+
+    fn explain_exact(&self, s: &str, buffer: &mut String) -> Option<Reason> {
+        if let Some(index) = &self.normalized {
+            let normalize =
+                |s: &str, normalized: &mut String| index.normalizer.normalize(s, normalized);
+            return explain_with(s, buffer, normalize, |normalized| {
+                index.entries.get(normalized).cloned()
+            });
+        }
+        match &self.registry {
+            Some(registry) => {
+                explain_with(s, buffer, fold_case, |lowercase| registry.get(lowercase))
+            }
+            None => explain_with(s, buffer, fold_case, |lowercase| {
+                builtin_entry(&self.builtin, lowercase)
+            }),
+        }
+    }
+}
+
+impl From<ThirteenRegistry> for Matcher {
+    fn from(registry: ThirteenRegistry) -> Self {
+        Arc::new(registry).into()
+    }
+}
+
+impl From<Arc<ThirteenRegistry>> for Matcher {
+    fn from(registry: Arc<ThirteenRegistry>) -> Self {
+        Self {
+            registry: Some(registry),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "matcher_test.rs"]
+mod matcher_test;
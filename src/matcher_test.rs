@@ -0,0 +1,185 @@
+use super::*;
+#[cfg(all(feature = "numerals", feature = "languages"))]
+use crate::fuzzy::Tolerance;
+#[cfg(feature = "languages")]
+use crate::normalizer::Normalizer;
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use crate::IsThirteen;
+
+const INPUTS: &[&str] = &[
+    "13",
+    "B",
+    "XIII",
+    "Thirteen",
+    "THIRTEEN",
+    "ΔΕΚΑΤΡΊΑ",
+    "IlIlIlIlIlIlI",
+    "aaaaaaaaaaaaa",
+    "",
+    "14",
+    "fourteen",
+    "ÄÄÄÄ",
+];
+
+#[test]
+fn same_as_scalar() {
+    let matcher = Matcher::new();
+    let inputs: Vec<&str> = INPUTS
+        .iter()
+        .chain(THIRTEEN_STRINGS.iter())
+        .copied()
+        .collect();
+    let expected: Vec<bool> = inputs.iter().map(|s| s.thirteen()).collect();
+    assert_eq!(matcher.mask(inputs.iter().copied()), expected);
+    assert_eq!(
+        matcher.count(inputs.iter().copied()),
+        expected.iter().filter(|&&b| b).count()
+    );
+    for &s in &inputs {
+        assert_eq!(matcher.is_match(s), s.thirteen(), "{}", s);
+        assert_eq!(matcher.explain(s), crate::explain(s));
+    }
+}
+
+#[cfg(all(feature = "numerals", feature = "politics"))]
+#[test]
+fn registry() {
+    let mut registry = ThirteenRegistry::new();
+    registry.insert("Floor 13");
+    registry.remove("pt");
+    let matcher = Matcher::from(registry);
+
+    assert_eq!(
+        matcher.explain("FLOOR 13"),
+        Some(Reason::Entry("floor 13".into()))
+    );
+    assert!(!matcher.is_match("PT"));
+    // Rules that do not depend on the dataset still apply
+    assert!(matcher.is_match("13"));
+    assert!(matcher.is_match("zzzzzzzzzzzzz"));
+    assert_eq!(
+        matcher.mask(["xiii", "pt", "floor 13"]),
+        [true, false, true]
+    );
+
+    let entries = matcher.entries();
+    assert!(entries.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(entries.contains(&"floor 13"));
+    assert_eq!(entries.len(), THIRTEEN_STRINGS.len());
+
+    let empty = Matcher::from(ThirteenRegistry::empty());
+    assert!(!empty.is_match("thirteen"));
+    assert!(empty.is_match("B"));
+}
+
+#[cfg(all(feature = "numerals", feature = "languages"))]
+#[test]
+fn categories() {
+    let matcher = Matcher::with_categories(Categories::NUMERALS | Categories::LANGUAGES);
+    assert_eq!(
+        matcher.mask(["XIII", "Treize", "Dilma", "Weedle", "13", "BBBBBBBBBBBBB"]),
+        [true, true, false, false, true, true]
+    );
+    assert!(matcher
+        .entries()
+        .iter()
+        .all(|entry| THIRTEEN_STRINGS.category(entry) != Some(Categories::POLITICS)));
+    assert_eq!(
+        Matcher::with_categories(Categories::all()).entries(),
+        Matcher::new().entries()
+    );
+}
+
+#[cfg(all(feature = "numerals", feature = "languages"))]
+#[test]
+fn normalizer() {
+    let matcher = Matcher::new().with_normalizer(Normalizer::unicode());
+    assert_eq!(
+        matcher.explain("TREIZIE\u{300}ME"),
+        Some(Reason::Entry("treizième".into()))
+    );
+    assert!(matcher.is_match("ＸＩＩＩ"));
+    assert!(matcher.is_match("１３"));
+    assert!(!matcher.is_match("treizieme"));
+    assert!(!Matcher::new().is_match("ＸＩＩＩ"));
+
+    let matcher = Matcher::new().with_normalizer(Normalizer::unicode().strip_diacritics(true));
+    assert_eq!(
+        matcher.explain("Threttán"),
+        Some(Reason::Entry("threttan".into()))
+    );
+    assert_eq!(
+        matcher.explain("שלש- עשרה"),
+        Some(Reason::Entry("ֹשְלֹש- עֶשְֹרֵה".into()))
+    );
+    assert!(matcher.is_match("TREIZIEME"));
+    assert!(matcher.is_match("aaaaaaaaaaaaa"));
+    assert!(!matcher.is_match("fourteen"));
+
+    let mut registry = ThirteenRegistry::empty();
+    registry.insert("Þrettán");
+    let matcher =
+        Matcher::from(registry).with_normalizer(Normalizer::unicode().strip_diacritics(true));
+    assert_eq!(
+        matcher.explain("THRETTAN"),
+        Some(Reason::Entry("þrettán".into()))
+    );
+    assert!(!matcher.is_match("xiii"));
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn normalizer_merges_entries() {
+    let strings: Vec<&str> = THIRTEEN_STRINGS.iter().copied().collect();
+    let matcher = Matcher::new().with_normalizer(Normalizer::unicode().strip_diacritics(true));
+    let merged = matcher.normalized.as_ref().unwrap().entries.len();
+    assert!(merged < strings.len());
+    // Every entry is still thirteen
+    assert!(matcher.mask(strings.iter().copied()).iter().all(|&b| b));
+
+    let matcher = Matcher::new().with_normalizer(Normalizer::new());
+    assert!(matcher.normalized.is_none());
+    assert_eq!(matcher.explain("Threttán"), None);
+}
+
+#[cfg(all(feature = "numerals", feature = "languages"))]
+#[test]
+fn fuzzy() {
+    let matcher = Matcher::new().with_fuzzy(Tolerance::default());
+    assert_eq!(
+        matcher.explain("Thirten"),
+        Some(Reason::Fuzzy {
+            entry: "thirteen".into(),
+            distance: 1
+        })
+    );
+    assert_eq!(matcher.explain("XIII"), Some(Reason::Entry("xiii".into())));
+    assert_eq!(
+        matcher.mask(["dreizen", "fourteen", "13"]),
+        [true, false, true]
+    );
+
+    let mut registry = ThirteenRegistry::empty();
+    registry.insert("Floor 13");
+    let matcher = Matcher::from(registry).with_fuzzy(Tolerance::default());
+    assert!(matcher.is_match("flor 13"));
+    assert!(!matcher.is_match("thirten"));
+
+    // The tree is rebuilt from normalized entries, in either order
+    let normalizer = Normalizer::unicode().strip_diacritics(true);
+    let expected = Some(Reason::Fuzzy {
+        entry: "threttan".into(),
+        distance: 1,
+    });
+    let matcher = Matcher::new()
+        .with_fuzzy(Tolerance::default())
+        .with_normalizer(normalizer);
+    assert_eq!(matcher.explain("Þretán"), expected);
+    let matcher = Matcher::new()
+        .with_normalizer(normalizer)
+        .with_fuzzy(Tolerance::default());
+    assert_eq!(matcher.explain("Þretán"), expected);
+    assert!(!Matcher::new()
+        .with_fuzzy(Tolerance::default())
+        .is_match("Þretán!"));
+}
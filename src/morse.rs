@@ -0,0 +1,364 @@
+//! Morse code. [`decode`] reads International Morse written in many ways: dots as `.`, `·` or
+//! `•`, dashes as `-`, `−`, `–` or `_`, any spacing between letters, and `/`, `|`, a new line or
+//! 3 spaces between words. Letters and digits that are not in Morse are kept as they are.
+//! American Morse and Wabun, the Japanese code, are supported by [`decode_with`]. [`Morse`]
+//! decodes a string and checks the result with `&str::thirteen`.
+//!
+//! ```
+//! # #[cfg(feature = "languages")] {
+//! use is_thirteen::morse::{decode, to_morse, Morse};
+//! use is_thirteen::IsThirteen;
+//!
+//! assert_eq!(to_morse(13).unwrap(), ".---- ...--");
+//! assert_eq!(decode("·−−−−  ···−−").unwrap(), "13");
+//! assert_eq!(decode("- .... .. / -.-. --- -.. .").unwrap(), "thi code");
+//! assert!(Morse::new("- .... i .-. - . . -.").thirteen());
+//! assert!(!Morse::new("..... ....").thirteen());
+//! # }
+//! ```
+
+use crate::IsThirteen;
+use std::fmt::Display;
+use unicode_normalization::UnicodeNormalization;
+
+/// A Morse code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alphabet {
+    /// International Morse code, ITU-R M.1677-1.
+    International,
+    /// American Morse code, which telegraphs used. Some letters have a gap inside, like `". ."`
+    /// for O, and L and 0 are long dashes, written `"—"` and `"——"`. When a gap could be inside
+    /// a letter or between two letters, it is read as inside.
+    American,
+    /// Wabun code, which decodes to katakana, and the International digits.
+    Wabun,
+}
+
+const INTERNATIONAL: &[(&str, char)] = &[
+    (".-", 'a'),
+    ("-...", 'b'),
+    ("-.-.", 'c'),
+    ("-..", 'd'),
+    (".", 'e'),
+    ("..-.", 'f'),
+    ("--.", 'g'),
+    ("....", 'h'),
+    ("..", 'i'),
+    (".---", 'j'),
+    ("-.-", 'k'),
+    (".-..", 'l'),
+    ("--", 'm'),
+    ("-.", 'n'),
+    ("---", 'o'),
+    (".--.", 'p'),
+    ("--.-", 'q'),
+    (".-.", 'r'),
+    ("...", 's'),
+    ("-", 't'),
+    ("..-", 'u'),
+    ("...-", 'v'),
+    (".--", 'w'),
+    ("-..-", 'x'),
+    ("-.--", 'y'),
+    ("--..", 'z'),
+    (".----", '1'),
+    ("..---", '2'),
+    ("...--", '3'),
+    ("....-", '4'),
+    (".....", '5'),
+    ("-....", '6'),
+    ("--...", '7'),
+    ("---..", '8'),
+    ("----.", '9'),
+    ("-----", '0'),
+    (".-.-.-", '.'),
+    ("--..--", ','),
+    ("..--..", '?'),
+    (".----.", '\''),
+    ("-.-.--", '!'),
+    ("-..-.", '/'),
+    ("-.--.", '('),
+    ("-.--.-", ')'),
+    (".-...", '&'),
+    ("---...", ':'),
+    ("-.-.-.", ';'),
+    ("-...-", '='),
+    (".-.-.", '+'),
+    ("-....-", '-'),
+    ("..--.-", '_'),
+    (".-..-.", '"'),
+    (".--.-.", '@'),
+];
+
+const AMERICAN: &[(&str, char)] = &[
+    (".-", 'a'),
+    ("-...", 'b'),
+    (".. .", 'c'),
+    ("-..", 'd'),
+    (".", 'e'),
+    (".-.", 'f'),
+    ("--.", 'g'),
+    ("....", 'h'),
+    ("..", 'i'),
+    ("-.-.", 'j'),
+    ("-.-", 'k'),
+    ("—", 'l'),
+    ("--", 'm'),
+    ("-.", 'n'),
+    (". .", 'o'),
+    (".....", 'p'),
+    ("..-.", 'q'),
+    (". ..", 'r'),
+    ("...", 's'),
+    ("-", 't'),
+    ("..-", 'u'),
+    ("...-", 'v'),
+    (".--", 'w'),
+    (".-..", 'x'),
+    (".. ..", 'y'),
+    ("... .", 'z'),
+    (".--.", '1'),
+    ("..-..", '2'),
+    ("...-.", '3'),
+    ("....-", '4'),
+    ("---", '5'),
+    ("......", '6'),
+    ("--..", '7'),
+    ("-....", '8'),
+    ("-..-", '9'),
+    ("——", '0'),
+];
+
+const WABUN: &[(&str, char)] = &[
+    (".-", 'イ'),
+    (".-.-", 'ロ'),
+    ("-...", 'ハ'),
+    ("-.-.", 'ニ'),
+    ("-..", 'ホ'),
+    (".", 'ヘ'),
+    ("..-..", 'ト'),
+    ("..-.", 'チ'),
+    ("--.", 'リ'),
+    ("....", 'ヌ'),
+    ("-.--.", 'ル'),
+    (".---", 'ヲ'),
+    ("-.-", 'ワ'),
+    (".-..", 'カ'),
+    ("--", 'ヨ'),
+    ("-.", 'タ'),
+    ("---", 'レ'),
+    ("---.", 'ソ'),
+    (".--.", 'ツ'),
+    ("--.-", 'ネ'),
+    (".-.", 'ナ'),
+    ("...", 'ラ'),
+    ("-", 'ム'),
+    ("..-", 'ウ'),
+    (".-..-", 'ヰ'),
+    ("..--", 'ノ'),
+    (".-...", 'オ'),
+    ("...-", 'ク'),
+    (".--", 'ヤ'),
+    ("-..-", 'マ'),
+    ("-.--", 'ケ'),
+    ("--..", 'フ'),
+    ("----", 'コ'),
+    ("-.---", 'エ'),
+    (".-.--", 'テ'),
+    ("--.--", 'ア'),
+    ("-.-.-", 'サ'),
+    ("-.-..", 'キ'),
+    ("-..--", 'ユ'),
+    ("-...-", 'メ'),
+    ("..-.-", 'ミ'),
+    ("--.-.", 'シ'),
+    (".--..", 'ヱ'),
+    ("--..-", 'ヒ'),
+    ("-..-.", 'モ'),
+    (".---.", 'セ'),
+    ("---.-", 'ス'),
+    (".-.-.", 'ン'),
+    (".--.-", 'ー'),
+    // Voiced and semi-voiced sound marks, which follow the kana they change
+    ("..", '\u{3099}'),
+    ("..--.", '\u{309a}'),
+    (".----", '1'),
+    ("..---", '2'),
+    ("...--", '3'),
+    ("....-", '4'),
+    (".....", '5'),
+    ("-....", '6'),
+    ("--...", '7'),
+    ("---..", '8'),
+    ("----.", '9'),
+    ("-----", '0'),
+];
+
+impl Alphabet {
+    fn table(self) -> &'static [(&'static str, char)] {
+        match self {
+            Alphabet::International => INTERNATIONAL,
+            Alphabet::American => AMERICAN,
+            Alphabet::Wabun => WABUN,
+        }
+    }
+
+    fn decode_letter(self, code: &str) -> Option<char> {
+        self.table()
+            .iter()
+            .find(|&&(letter_code, _)| letter_code == code)
+            .map(|&(_, letter)| letter)
+    }
+
+    fn encode_letter(self, letter: char) -> Option<&'static str> {
+        self.table()
+            .iter()
+            .find(|&&(_, table_letter)| table_letter == letter)
+            .map(|&(code, _)| code)
+    }
+}
+
+/// `Morse` is thirteen if it decodes to a thirteen string.
+#[derive(Debug, Clone, Copy)]
+pub struct Morse<'s> {
+    s: &'s str,
+    alphabet: Alphabet,
+}
+
+impl<'s> Morse<'s> {
+    /// Uses [`Alphabet::International`].
+    pub fn new(s: &'s str) -> Self {
+        Self::with_alphabet(s, Alphabet::International)
+    }
+
+    pub fn with_alphabet(s: &'s str, alphabet: Alphabet) -> Self {
+        Self { s, alphabet }
+    }
+
+    /// Returns the decoded string, or `None` if the string is not Morse code.
+    pub fn decoded(&self) -> Option<String> {
+        decode_with(self.s, self.alphabet)
+    }
+}
+
+impl IsThirteen for Morse<'_> {
+    fn thirteen(&self) -> bool {
+        matches!(self.decoded(), Some(decoded) if decoded.as_str().thirteen())
+    }
+}
+
+/// Decodes International Morse code. Returns `None` if `s` has a code that is not a letter, or
+/// no code at all. Letters are decoded to lowercase.
+pub fn decode(s: &str) -> Option<String> {
+    decode_with(s, Alphabet::International)
+}
+
+/// Decodes Morse code in `alphabet`.
+pub fn decode_with(s: &str, alphabet: Alphabet) -> Option<String> {
+    let mut decoded = String::new();
+    let mut has_code = false;
+    for (i, word) in words(s, alphabet).iter().enumerate() {
+        if i > 0 {
+            decoded.push(' ');
+        }
+        let mut rest = &word[..];
+        while let Some((token, tail)) = rest.split_first() {
+            if !token.chars().all(is_symbol) {
+                // Plain text between the codes
+                if token.chars().any(is_symbol) {
+                    return None;
+                }
+                decoded.extend(token.chars().flat_map(char::to_lowercase));
+                rest = tail;
+                continue;
+            }
+            has_code = true;
+            // A letter with a gap inside, which only American Morse has
+            if let (Alphabet::American, Some(next)) = (alphabet, tail.first()) {
+                let spaced = format!("{} {}", token, next);
+                if let Some(letter) = alphabet.decode_letter(&spaced) {
+                    decoded.push(letter);
+                    rest = &tail[1..];
+                    continue;
+                }
+            }
+            decoded.push(alphabet.decode_letter(token)?);
+            rest = tail;
+        }
+    }
+    if !has_code {
+        return None;
+    }
+    Some(match alphabet {
+        // Joins the sound marks to their kana
+        Alphabet::Wabun => decoded.nfc().collect(),
+        _ => decoded,
+    })
+}
+
+fn is_symbol(c: char) -> bool {
+    matches!(c, '.' | '-' | '—')
+}
+
+/// Splits `s` into words of codes, with every way to write a dot or a dash replaced by `.` or
+/// `-`, or `—` for the long dash of American Morse.
+fn words(s: &str, alphabet: Alphabet) -> Vec<Vec<String>> {
+    let mut words = vec![Vec::new()];
+    let mut token = String::new();
+    let mut spaces = 0;
+    for c in s.chars() {
+        let word_break = matches!(c, '/' | '|' | '\n');
+        if word_break || c.is_whitespace() {
+            if !token.is_empty() {
+                words.last_mut().unwrap().push(std::mem::take(&mut token));
+            }
+            spaces += 1;
+            if (word_break || spaces == 3) && !words.last().unwrap().is_empty() {
+                words.push(Vec::new());
+            }
+            continue;
+        }
+        spaces = 0;
+        match c {
+            '.' | '·' | '•' | '∙' | '⋅' | '*' => token.push('.'),
+            '-' | '−' | '–' | '_' => token.push('-'),
+            '—' | '⸺' if alphabet == Alphabet::American => token.push('—'),
+            '⸻' if alphabet == Alphabet::American => token.push_str("——"),
+            '—' | '⸺' | '⸻' => token.push('-'),
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        words.last_mut().unwrap().push(token);
+    }
+    if words.last().unwrap().is_empty() {
+        words.pop();
+    }
+    words
+}
+
+/// Encodes `value` in International Morse code, with a space between letters and `" / "` between
+/// words. Returns `None` if a character has no code.
+pub fn to_morse<T: Display>(value: T) -> Option<String> {
+    to_morse_with(value, Alphabet::International)
+}
+
+/// Encodes `value` in `alphabet`.
+pub fn to_morse_with<T: Display>(value: T, alphabet: Alphabet) -> Option<String> {
+    let text = value.to_string();
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        let mut codes = Vec::new();
+        // Decomposes kana with sound marks, which Wabun sends separately
+        for c in word.nfd() {
+            let letter = c.to_lowercase().next().unwrap_or(c);
+            codes.push(alphabet.encode_letter(letter)?);
+        }
+        words.push(codes.join(" "));
+    }
+    Some(words.join(" / "))
+}
+
+#[cfg(test)]
+#[path = "morse_test.rs"]
+mod morse_test;
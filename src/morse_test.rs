@@ -0,0 +1,81 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case(".---- ...--", Some("13"))]
+#[case(".----   ...--", Some("1 3"))]
+#[case("  .----  ...--\n", Some("13"))]
+#[case("·−−−− ···−−", Some("13"))]
+#[case("•---- •••__", Some("13"))]
+#[case("- .... .. .-. - . . -.", Some("thirteen"))]
+#[case("- .... i .-. - E . -.", Some("thirteen"))]
+#[case("- .... .. / -.-. --- -.. .", Some("thi code"))]
+#[case("- .... ..|-.-. --- -.. .", Some("thi code"))]
+#[case("-.-.-- .--.-.", Some("!@"))]
+#[case("thirteen", None)]
+#[case("", None)]
+#[case("........", None)]
+#[case(".-x", None)]
+fn international(#[case] s: &str, #[case] expected: Option<&str>) {
+    assert_eq!(decode(s).as_deref(), expected);
+}
+
+#[rstest]
+#[case(".--. ...-.", Alphabet::American, Some("13"))]
+#[case(". . . ..", Alphabet::American, Some("or"))]
+#[case("— ——", Alphabet::American, Some("l0"))]
+#[case("⸺ ⸻", Alphabet::American, Some("l0"))]
+#[case(".---- ...--", Alphabet::Wabun, Some("13"))]
+#[case("-.-.- ..", Alphabet::Wabun, Some("ザ"))]
+#[case("-..-- .. ..- -.-.- .-.-.", Alphabet::Wabun, Some("ユ\u{3099}ウサン"))]
+#[case("-.-.- ..--.", Alphabet::Wabun, Some("サ\u{309a}"))]
+#[case("---.-.", Alphabet::Wabun, None)]
+fn other_alphabets(#[case] s: &str, #[case] alphabet: Alphabet, #[case] expected: Option<&str>) {
+    assert_eq!(decode_with(s, alphabet).as_deref(), expected);
+}
+
+#[rstest]
+#[case(13, Alphabet::International, Some(".---- ...--"))]
+#[case("Thirteen", Alphabet::International, Some("- .... .. .-. - . . -."))]
+#[case(
+    "baker's dozen",
+    Alphabet::International,
+    Some("-... .- -.- . .-. .----. ... / -.. --- --.. . -.")
+)]
+#[case("#13", Alphabet::International, None)]
+#[case(13, Alphabet::American, Some(".--. ...-."))]
+#[case("ザ", Alphabet::Wabun, Some("-.-.- .."))]
+fn encoding<T: Display>(
+    #[case] value: T,
+    #[case] alphabet: Alphabet,
+    #[case] expected: Option<&str>,
+) {
+    assert_eq!(to_morse_with(value, alphabet).as_deref(), expected);
+}
+
+#[test]
+fn round_trip() {
+    for alphabet in [Alphabet::International, Alphabet::American, Alphabet::Wabun] {
+        for &(code, letter) in alphabet.table() {
+            let encoded = to_morse_with(letter, alphabet).unwrap();
+            assert_eq!(encoded, code);
+            assert_eq!(decode_with(&encoded, alphabet), Some(letter.to_string()));
+        }
+    }
+}
+
+#[rstest]
+#[case(Morse::new(".---- ...--"), true)]
+#[case(Morse::new("·−−−−   ···−−"), false)]
+#[cfg_attr(
+    feature = "languages",
+    case(Morse::new("- .... .. .-. - . . -."), true)
+)]
+#[case(Morse::new("- .... .. .-. - . . -. ..-. .-.."), false)]
+#[cfg_attr(feature = "numerals", case(Morse::new("-..- .. .. .."), true))]
+#[case(Morse::new("xiii"), false)]
+#[case(Morse::with_alphabet(".--. ...-.", Alphabet::American), true)]
+#[case(Morse::with_alphabet(".--. ...-.", Alphabet::International), false)]
+fn wrapper(#[case] morse: Morse, #[case] expected: bool) {
+    assert_eq!(morse.thirteen(), expected);
+}
@@ -0,0 +1,150 @@
+//! Configurable normalization of strings before they are looked up in the dataset.
+//! `&str::thirteen` only lowercases, so `"treizième"` written with a combining grave accent, or
+//! `"１３"` written in full-width digits, is not recognized. A [`Matcher`] with a [`Normalizer`]
+//! applies the same pipeline to its input and to the dataset, so such variants match.
+//!
+//! ```
+//! # #[cfg(feature = "languages")] {
+//! use is_thirteen::matcher::Matcher;
+//! use is_thirteen::normalizer::Normalizer;
+//!
+//! let matcher = Matcher::new().with_normalizer(Normalizer::unicode());
+//! assert!(matcher.is_match("TREIZIE\u{300}ME"));
+//! assert!(!Matcher::new().is_match("TREIZIE\u{300}ME"));
+//!
+//! let matcher = Matcher::new().with_normalizer(Normalizer::unicode().strip_diacritics(true));
+//! assert!(matcher.is_match("Þrettán"));
+//! assert!(matcher.is_match("threttán"));
+//! # }
+//! ```
+//!
+//! [`Matcher`]: crate::matcher::Matcher
+
+use caseless::Caseless;
+use std::borrow::Cow;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// How a [`Normalizer`] folds case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseFolding {
+    /// `str::to_lowercase()`, which `&str::thirteen` uses.
+    Lowercase,
+    /// Full Unicode case folding, which also maps `"ß"` to `"ss"` and `"ς"` to `"σ"`.
+    Full,
+}
+
+/// A normalization pipeline. The steps run in this order: NFKC, case folding, diacritic
+/// stripping. [`Normalizer::new`] only lowercases, like `&str::thirteen`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    nfkc: bool,
+    case_folding: CaseFolding,
+    strip_diacritics: bool,
+}
+
+impl Normalizer {
+    /// Returns a normalizer that only lowercases.
+    pub const fn new() -> Self {
+        Self {
+            nfkc: false,
+            case_folding: CaseFolding::Lowercase,
+            strip_diacritics: false,
+        }
+    }
+
+    /// Returns a normalizer that applies NFKC and full case folding.
+    pub const fn unicode() -> Self {
+        Self::new().nfkc(true).case_folding(CaseFolding::Full)
+    }
+
+    /// Applies NFKC, which composes combining characters and replaces compatibility characters,
+    /// like full-width digits and ligatures, with their plain forms.
+    pub const fn nfkc(mut self, nfkc: bool) -> Self {
+        self.nfkc = nfkc;
+        self
+    }
+
+    pub const fn case_folding(mut self, case_folding: CaseFolding) -> Self {
+        self.case_folding = case_folding;
+        self
+    }
+
+    /// Removes every combining mark, which includes accents, Hebrew niqqud and the vowel signs of
+    /// Indic scripts, and writes letters like `"ø"` and `"þ"` without their stroke.
+    pub const fn strip_diacritics(mut self, strip_diacritics: bool) -> Self {
+        self.strip_diacritics = strip_diacritics;
+        self
+    }
+
+    /// Writes the normalized version of `s` to `normalized`.
+    pub fn normalize(&self, s: &str, normalized: &mut String) {
+        normalized.clear();
+        if s.is_ascii() {
+            // Every step but case folding leaves ASCII unchanged, and both foldings agree on it
+            normalized.extend(s.chars().map(|c| c.to_ascii_lowercase()));
+            return;
+        }
+
+        let composed = if self.nfkc {
+            Cow::Owned(s.nfkc().collect())
+        } else {
+            Cow::Borrowed(s)
+        };
+        let mut text = match self.case_folding {
+            CaseFolding::Lowercase => composed.to_lowercase(),
+            CaseFolding::Full => composed.chars().default_case_fold().collect(),
+        };
+        if self.strip_diacritics {
+            let mut stripped = String::with_capacity(text.len());
+            for c in text.nfd().filter(|&c| !is_combining_mark(c)) {
+                match without_stroke(c) {
+                    Some(letters) => stripped.push_str(letters),
+                    None => stripped.push(c),
+                }
+            }
+            text = stripped;
+        }
+
+        // Folding can leave text that is not normalized, and decomposing splits Hangul syllables
+        if self.nfkc {
+            normalized.extend(text.nfkc());
+        } else if self.strip_diacritics {
+            normalized.extend(text.nfc());
+        } else {
+            normalized.push_str(&text);
+        }
+    }
+
+    /// Returns the normalized version of `s`.
+    pub fn normalized(&self, s: &str) -> String {
+        let mut normalized = String::new();
+        self.normalize(s, &mut normalized);
+        normalized
+    }
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Spells lowercase letters that have no decomposition without their stroke or ligature.
+fn without_stroke(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ø' => "o",
+        'ł' => "l",
+        'đ' | 'ð' => "d",
+        'ħ' => "h",
+        'ı' => "i",
+        'þ' => "th",
+        'æ' => "ae",
+        'œ' => "oe",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+#[path = "normalizer_test.rs"]
+mod normalizer_test;
@@ -0,0 +1,46 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case(Normalizer::new(), "XIII", "xiii")]
+#[case(Normalizer::new(), "TREIZIE\u{300}ME", "treizie\u{300}me")]
+#[case(Normalizer::new(), "１３", "１３")]
+#[case(Normalizer::new(), "STRAẞE", "straße")]
+#[case(Normalizer::unicode(), "XIII", "xiii")]
+#[case(Normalizer::unicode(), "TREIZIE\u{300}ME", "treizième")]
+#[case(Normalizer::unicode(), "１３", "13")]
+#[case(Normalizer::unicode(), "Ⅻ", "xii")]
+#[case(Normalizer::unicode(), "STRAẞE", "strasse")]
+#[case(Normalizer::unicode(), "ΔΕΚΑΤΡΕΊΣ", "δεκατρείσ")]
+#[case(Normalizer::unicode(), "열셋", "열셋")]
+#[case(Normalizer::unicode().strip_diacritics(true), "Þrettán", "threttan")]
+#[case(Normalizer::unicode().strip_diacritics(true), "treizième", "treizieme")]
+#[case(Normalizer::unicode().strip_diacritics(true), "열셋", "열셋")]
+#[case(
+    Normalizer::unicode().strip_diacritics(true),
+    "\u{5b9}שְלֹש- עֶשְֹרֵה",
+    "שלש- עשרה"
+)]
+#[case(Normalizer::new().strip_diacritics(true), "Ø", "o")]
+fn normalize(#[case] normalizer: Normalizer, #[case] s: &str, #[case] expected: &str) {
+    assert_eq!(normalizer.normalized(s), expected);
+}
+
+#[test]
+fn reuses_buffer() {
+    let normalizer = Normalizer::unicode();
+    let mut normalized = String::from("stale");
+    normalizer.normalize("TREIZE", &mut normalized);
+    assert_eq!(normalized, "treize");
+    normalizer.normalize("ＴＲＥＩＺＥ", &mut normalized);
+    assert_eq!(normalized, "treize");
+}
+
+#[test]
+fn default() {
+    assert_eq!(Normalizer::default(), Normalizer::new());
+    assert_eq!(
+        Normalizer::unicode(),
+        Normalizer::new().nfkc(true).case_folding(CaseFolding::Full)
+    );
+}
@@ -0,0 +1,61 @@
+//! Checks for rayon parallel iterators.
+//!
+//! ```
+//! use is_thirteen::parallel::ParallelThirteen;
+//! use rayon::prelude::*;
+//!
+//! let values: Vec<i64> = (0..1000).collect();
+//! assert_eq!(values.par_iter().copied().par_count_thirteen(), 1);
+//! ```
+
+use crate::matcher::Matcher;
+use crate::IsThirteen;
+use rayon::iter::Filter;
+use rayon::prelude::*;
+
+/// Strings per task in [`par_mask`].
+const CHUNK: usize = 1024;
+
+/// Adds thirteen checks to every [`ParallelIterator`].
+pub trait ParallelThirteen: ParallelIterator {
+    /// Keeps the items that are thirteen.
+    fn par_filter_thirteen(self) -> Filter<Self, fn(&Self::Item) -> bool>
+    where
+        Self::Item: IsThirteen,
+    {
+        self.filter(IsThirteen::thirteen)
+    }
+
+    /// Returns the number of items that are thirteen.
+    fn par_count_thirteen(self) -> usize
+    where
+        Self::Item: IsThirteen,
+    {
+        self.par_filter_thirteen().count()
+    }
+}
+
+impl<I: ParallelIterator> ParallelThirteen for I {}
+
+/// Returns whether each string is thirteen, in the same order as `strings`.
+pub fn par_mask<S>(strings: &[S]) -> Vec<bool>
+where
+    S: AsRef<str> + Sync,
+{
+    par_mask_with(&Matcher::new(), strings)
+}
+
+/// [`par_mask`], checking strings with `matcher`.
+pub fn par_mask_with<S>(matcher: &Matcher, strings: &[S]) -> Vec<bool>
+where
+    S: AsRef<str> + Sync,
+{
+    strings
+        .par_chunks(CHUNK)
+        .flat_map_iter(|chunk| matcher.mask(chunk.iter().map(AsRef::as_ref)))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "parallel_test.rs"]
+mod parallel_test;
@@ -0,0 +1,32 @@
+use super::*;
+use crate::registry::ThirteenRegistry;
+
+#[test]
+fn filter_and_count() {
+    let strings = vec!["13", "XIII", "14", "thirteen", "fourteen"];
+    let mut thirteens: Vec<&str> = strings.par_iter().copied().par_filter_thirteen().collect();
+    thirteens.sort_unstable();
+    assert_eq!(thirteens, ["13", "XIII", "thirteen"]);
+    assert_eq!(strings.into_par_iter().par_count_thirteen(), 3);
+
+    assert_eq!((0..100_000i32).into_par_iter().par_count_thirteen(), 1);
+}
+
+#[test]
+fn mask_keeps_order() {
+    let strings: Vec<String> = (0..10_000).map(|n| (n % 20).to_string()).collect();
+    let expected: Vec<bool> = strings.iter().map(|s| s.thirteen()).collect();
+    assert_eq!(par_mask(&strings), expected);
+    assert!(par_mask::<&str>(&[]).is_empty());
+}
+
+#[test]
+fn mask_with_registry() {
+    let mut registry = ThirteenRegistry::new();
+    registry.insert("Floor 13");
+    let matcher = Matcher::from(registry);
+    assert_eq!(
+        par_mask_with(&matcher, &["floor 13", "floor 14"]),
+        [true, false]
+    );
+}
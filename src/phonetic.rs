@@ -0,0 +1,234 @@
+//! Phonetic matching, for speech-to-text transcripts that spell thirteen strings the way they
+//! sound. [`SoundsLike`] compares phonetic codes instead of spellings:
+//!
+//! - Double Metaphone for English and for names, which are most entries without a language
+//! - Kölner Phonetik for German entries
+//! - IPA, like the entry `"θərˈtiːn"`, is converted to Double Metaphone's alphabet, so IPA input
+//!   and IPA entries are compared to the other two kinds
+//!
+//! Spaces and punctuation are ignored, so `"thur teen"` sounds like `"thirteen"`. The codes
+//! leave out vowels after the first letter, so `"threaten"` has the same code as `"thirteen"`; a
+//! string must also be within one edit for every 4 letters of the entry it sounds like. Only
+//! entries in [`Categories::LANGUAGES`] and [`Categories::POP_CULTURE`] are compared, and only if
+//! their code has at least 4 characters: more words than not sound like `"xiii"` (`S`) or `"pt"`
+//! (`PT`).
+//!
+//! ```
+//! # #[cfg(all(feature = "languages", feature = "pop-culture"))] {
+//! use is_thirteen::phonetic::SoundsLike;
+//! use is_thirteen::{IsThirteen, Reason};
+//!
+//! assert!(SoundsLike("thur teen").thirteen());
+//! assert!(SoundsLike("Jody Wittaker").thirteen());
+//! assert_eq!(
+//!     SoundsLike("thirdeen").explain(),
+//!     Some(Reason::SoundsLike("thirteen".into()))
+//! );
+//! assert!(!SoundsLike("fourteen").thirteen());
+//! assert!(!SoundsLike("thirty one").thirteen());
+//! # }
+//! ```
+
+use crate::fuzzy::{distance, Tolerance};
+use crate::thirteen_strings::{Categories, THIRTEEN_STRINGS};
+use crate::{explain, IsThirteen, Reason};
+use fnv::FnvHashMap as HashMap;
+use once_cell::sync::OnceCell;
+use rphonetic::{Cologne, DoubleMetaphone, Encoder};
+use std::borrow::Cow;
+
+/// The minimum length of the phonetic code of an entry that [`SoundsLike`] compares to.
+const MIN_CODE_LEN: usize = 4;
+
+/// The length that Double Metaphone codes are cut to. The default of 4 would make every string
+/// that starts like `"thirteen"`, such as `"thirty nine"`, sound like it.
+const MAX_CODE_LEN: usize = 32;
+
+/// The edits between the spellings of a string and the entry it sounds like. Looser than
+/// [`Tolerance::default`], because the codes must match too.
+const SPELLING_TOLERANCE: Tolerance = Tolerance::scaled(4, 3);
+
+/// `SoundsLike` is thirteen if it is thirteen, or if it sounds like an entry of
+/// [`THIRTEEN_STRINGS`].
+#[derive(Debug, Clone, Copy)]
+pub struct SoundsLike<'s>(pub &'s str);
+
+impl SoundsLike<'_> {
+    /// Returns why the string is thirteen, or `None` if it is not. Strings that are thirteen as
+    /// spelled get the same reason as from [`explain`](crate::explain), and other strings get
+    /// [`Reason::SoundsLike`] with the entry they sound like.
+    pub fn explain(&self) -> Option<Reason> {
+        explain(self.0).or_else(|| {
+            let index = Index::builtin();
+            let entry = match ipa(self.0) {
+                Some(code) => index.metaphone.get(&code).copied(),
+                None => {
+                    let (primary, alternate) = metaphone(self.0).unzip();
+                    primary
+                        .into_iter()
+                        .chain(alternate)
+                        .filter_map(|code| index.metaphone.get(&code))
+                        .chain(cologne(self.0).and_then(|code| index.cologne.get(&code)))
+                        .copied()
+                        .find(|entry| spelled_close(self.0, entry))
+                }
+            };
+            entry.map(|entry| Reason::SoundsLike(Cow::Borrowed(entry)))
+        })
+    }
+}
+
+impl IsThirteen for SoundsLike<'_> {
+    fn thirteen(&self) -> bool {
+        self.explain().is_some()
+    }
+}
+
+/// The compared entries, keyed by their phonetic codes.
+#[derive(Debug, Default)]
+struct Index {
+    metaphone: HashMap<String, &'static str>,
+    cologne: HashMap<String, &'static str>,
+}
+
+impl Index {
+    fn builtin() -> &'static Index {
+        static BUILTIN: OnceCell<Index> = OnceCell::new();
+        BUILTIN.get_or_init(|| {
+            let categories = Categories::LANGUAGES | Categories::POP_CULTURE;
+            let mut entries: Vec<_> = THIRTEEN_STRINGS
+                .iter()
+                .filter_map(|&entry| Some((entry, THIRTEEN_STRINGS.metadata(entry)?)))
+                .filter(|(_, metadata)| categories.contains(metadata.category))
+                .collect();
+            // Entries that sound the same are reported in the first category, then by spelling
+            entries.sort_unstable_by_key(|&(entry, metadata)| (metadata.category.bits(), entry));
+
+            let mut index = Index::default();
+            for (entry, metadata) in entries {
+                let insert = |codes: &mut HashMap<String, &'static str>, code: String| {
+                    if code.chars().count() >= MIN_CODE_LEN {
+                        codes.entry(code).or_insert(entry);
+                    }
+                };
+                if let Some(code) = ipa(entry) {
+                    insert(&mut index.metaphone, code);
+                    continue;
+                }
+                match metadata.language {
+                    None | Some("English") => {
+                        if let Some((primary, alternate)) = metaphone(entry) {
+                            insert(&mut index.metaphone, primary);
+                            insert(&mut index.metaphone, alternate);
+                        }
+                    }
+                    Some("German") | Some("Swiss German") | Some("Luxembourgish") => {
+                        if let Some(code) = cologne(entry) {
+                            insert(&mut index.cologne, code);
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+            index
+        })
+    }
+}
+
+/// Returns the primary and alternate Double Metaphone codes of the ASCII letters in `s`.
+fn metaphone(s: &str) -> Option<(String, String)> {
+    let letters: String = s.chars().filter(char::is_ascii_alphabetic).collect();
+    if letters.is_empty() {
+        return None;
+    }
+    let codes = DoubleMetaphone::new(Some(MAX_CODE_LEN)).double_metaphone(&letters);
+    Some((codes.primary(), codes.alternate()))
+}
+
+/// Returns `true` if the letters of `s` are within [`SPELLING_TOLERANCE`] of the letters of
+/// `entry`, ignoring case. IPA input is not compared this way.
+fn spelled_close(s: &str, entry: &str) -> bool {
+    let letters = |s: &str| -> String {
+        s.chars()
+            .filter(|c| c.is_alphabetic())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let s = letters(s);
+    distance(&s, &letters(entry)) <= SPELLING_TOLERANCE.max_distance(s.chars().count())
+}
+
+/// Returns the Kölner Phonetik code of the letters in `s`.
+fn cologne(s: &str) -> Option<String> {
+    let letters: String = s.chars().filter(|c| c.is_alphabetic()).collect();
+    let code = Cologne.encode(&letters);
+    if code.is_empty() {
+        None
+    } else {
+        Some(code)
+    }
+}
+
+/// Converts `s` to the alphabet of Double Metaphone if it is IPA, which is recognized by symbols
+/// that English spelling does not use. Like Double Metaphone, vowels are only kept at the start,
+/// as `A`.
+fn ipa(s: &str) -> Option<String> {
+    const IPA_ONLY: &str = "ˈˌːəɚɜɝɪʊʌɛɔɑɒæθðʃʒŋɹɾɡʔ";
+    if !s.chars().any(|c| IPA_ONLY.contains(c)) {
+        return None;
+    }
+
+    let mut code = String::new();
+    // The consonant that was just written, which is written once if it is doubled
+    let mut previous = "";
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let sound = match c {
+            'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'j' | 'w' | 'æ' | 'ɐ' | 'ɑ' | 'ɒ' | 'ɔ' | 'ə'
+            | 'ɛ' | 'ɜ' | 'ɪ' | 'ɨ' | 'ʉ' | 'ʊ' | 'ʌ' | 'ø' | 'œ' | 'ɯ' | 'ɤ' => {
+                previous = "";
+                if code.is_empty() {
+                    code.push('A');
+                }
+                continue;
+            }
+            'p' | 'b' => "P",
+            't' if chars.peek() == Some(&'ʃ') => {
+                chars.next();
+                "X"
+            }
+            'd' if chars.peek() == Some(&'ʒ') => {
+                chars.next();
+                "J"
+            }
+            't' | 'd' | 'ɾ' => "T",
+            'k' | 'g' | 'ɡ' | 'x' => "K",
+            'f' | 'v' => "F",
+            'θ' | 'ð' => "0",
+            's' | 'z' => "S",
+            'ʃ' => "X",
+            'ʒ' => "J",
+            'h' => "H",
+            'm' => "M",
+            'n' => "N",
+            'ŋ' => "NK",
+            'l' => "L",
+            // Including the r-colored vowels of American English
+            'r' | 'ɹ' | 'ɻ' | 'ʁ' | 'ɚ' | 'ɝ' => "R",
+            // Stress, length, syllable breaks, brackets and anything unknown
+            _ => "",
+        };
+        // `ŋɡ` is `NK` like the spelling "ng", not `NKK`
+        if !previous.ends_with(sound) {
+            code.push_str(sound);
+        }
+        if !sound.is_empty() {
+            previous = sound;
+        }
+    }
+    Some(code)
+}
+
+#[cfg(test)]
+#[path = "phonetic_test.rs"]
+mod phonetic_test;
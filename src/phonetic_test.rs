@@ -0,0 +1,66 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case("thur teen", Some("thirteen"))]
+#[case("THIRDEEN", Some("thirteen"))]
+#[case("Jody Wittaker", Some("jodie whittaker"))]
+#[case("Sharon Karter", Some("sharon carter"))]
+#[case("bakers dozzen", Some("baker's dozen"))]
+#[case("dreizen", Some("dreizehn"))]
+#[case("θɝˈtin", Some("thirteen"))]
+#[case("fourteen", None)]
+#[case("thirty", None)]
+#[case("thirty one", None)]
+#[case("thirty nine", None)]
+#[case("threaten", None)]
+#[case("treys", None)]
+#[case("sea", None)]
+#[case("", None)]
+fn sounds_like(#[case] s: &str, #[case] expected: Option<&'static str>) {
+    let expected = expected.map(|entry| Reason::SoundsLike(entry.into()));
+    assert_eq!(SoundsLike(s).explain(), expected);
+    assert_eq!(SoundsLike(s).thirteen(), expected.is_some());
+}
+
+#[test]
+fn exact_reasons() {
+    assert_eq!(
+        SoundsLike("Thirteen").explain(),
+        Some(Reason::Entry("thirteen".into()))
+    );
+    assert_eq!(SoundsLike("B").explain(), Some(Reason::Literal));
+    assert_eq!(
+        SoundsLike("thur teen").explain().unwrap().to_string(),
+        "sounds like entry \"thirteen\""
+    );
+}
+
+#[rstest]
+#[case("θərˈtiːn", Some("0RTN"))]
+#[case("ˈθɜːtiːn", Some("0TN"))]
+#[case("ˈtʃɜːtʃ", Some("XX"))]
+#[case("ˈsɪti", Some("ST"))]
+#[case("ˈæŋɡəl", Some("ANKL"))]
+#[case("thirteen", None)]
+fn ipa_codes(#[case] s: &str, #[case] expected: Option<&str>) {
+    assert_eq!(ipa(s).as_deref(), expected);
+}
+
+#[test]
+fn index() {
+    let index = Index::builtin();
+    // The IPA entry sounds like the English one, which is reported
+    assert_eq!(index.metaphone.get("0RTN"), Some(&"thirteen"));
+    assert_eq!(metaphone("thirteen").unwrap().0, "0RTN");
+    assert_eq!(metaphone("thirty nine").unwrap().0, "0RTNN");
+    assert_eq!(cologne("Dreizehn").as_deref(), Some("2786"));
+    assert!(index
+        .metaphone
+        .keys()
+        .chain(index.cologne.keys())
+        .all(|code| code.len() >= MIN_CODE_LEN));
+    // Entries in other languages and categories are left out
+    assert!(!SoundsLike("treyz").thirteen());
+    assert!(!SoundsLike("dilmah").thirteen());
+}
@@ -0,0 +1,187 @@
+//! Python extension module. Build a `cdylib` with
+//! `cargo rustc --lib --features python --crate-type cdylib` and rename `libis_thirteen.so` to
+//! `is_thirteen.so` (or `is_thirteen.pyd` on Windows) to import it.
+//!
+//! ```python
+//! from is_thirteen import is_thirteen, is_thirteen_many, Roughly
+//!
+//! is_thirteen("XIII")             # True
+//! is_thirteen_many([13, 14.0])    # [True, False]
+//! Roughly(12.7).thirteen()        # True
+//! ```
+
+use crate::{
+    AnagramOf, AtomicNumber, Backwards, CanSpell, DivisibleBy, IsThirteen, Roughly, Within,
+};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyComplex, PyFloat, PyInt, PyString};
+
+/// Returns `True` if `obj` is thirteen. Accepts `int`, `float`, `str`, `bytes`, `complex` and
+/// `None`.
+#[pyfunction]
+#[pyo3(name = "is_thirteen")]
+fn py_is_thirteen(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if obj.is_none() {
+        Ok(().thirteen())
+    } else if let Ok(b) = obj.downcast::<PyBool>() {
+        // bool is a subclass of int, so it has to be checked first
+        Ok(b.is_true().thirteen())
+    } else if obj.is_instance_of::<PyInt>() {
+        // Integers that do not fit in i128 are not thirteen
+        Ok(obj.extract::<i128>().is_ok_and(|n| n.thirteen()))
+    } else if let Ok(f) = obj.downcast::<PyFloat>() {
+        Ok(f.value().thirteen())
+    } else if let Ok(s) = obj.downcast::<PyString>() {
+        Ok(s.to_str()?.thirteen())
+    } else if let Ok(b) = obj.downcast::<PyBytes>() {
+        Ok(std::str::from_utf8(b.as_bytes()).is_ok_and(|s| s.thirteen()))
+    } else if let Ok(c) = obj.downcast::<PyComplex>() {
+        Ok(complex_thirteen(c.real(), c.imag()))
+    } else {
+        Err(PyTypeError::new_err(format!(
+            "cannot compare {} to thirteen",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+/// Complex numbers follow the imaginary thirteens in the dataset: `13+0i`, `13i` and `13 + 13i`.
+fn complex_thirteen(real: f64, imag: f64) -> bool {
+    match (real.thirteen(), imag.thirteen()) {
+        (true, false) => imag == 0.0,
+        (false, true) => real == 0.0,
+        (true, true) => true,
+        (false, false) => false,
+    }
+}
+
+/// Calls `is_thirteen()` on every element of `iterable`.
+#[pyfunction]
+fn is_thirteen_many(iterable: &Bound<'_, PyAny>) -> PyResult<Vec<bool>> {
+    iterable
+        .try_iter()?
+        .map(|obj| py_is_thirteen(&obj?))
+        .collect()
+}
+
+/// Generates a Python class that stores the constructor arguments and checks them with one of the
+/// crate's wrapper types.
+macro_rules! py_wrapper {
+    (
+        $py_type:ident($name:literal, $field:ty),
+        new($($arg:ident: $arg_type:ty),*) => $init:expr,
+        |$v:ident| $wrapper:expr
+    ) => {
+        #[pyclass(name = $name, frozen)]
+        struct $py_type($field);
+
+        #[pymethods]
+        impl $py_type {
+            #[new]
+            fn new($($arg: $arg_type),*) -> Self {
+                Self($init)
+            }
+
+            fn thirteen(&self) -> bool {
+                let $v = &self.0;
+                $wrapper.thirteen()
+            }
+
+            fn __bool__(&self) -> bool {
+                self.thirteen()
+            }
+
+            fn __repr__(&self) -> String {
+                let $v = &self.0;
+                format!("{:?}", $wrapper)
+            }
+        }
+    };
+}
+
+py_wrapper!(
+    PyRoughly("Roughly", f64),
+    new(value: f64) => value,
+    |v| Roughly(*v)
+);
+py_wrapper!(
+    PyWithin("Within", (f64, f64)),
+    new(value: f64, radius: f64) => (value, radius),
+    |v| Within::new(v.0, v.1)
+);
+py_wrapper!(
+    PyCanSpell("CanSpell", CanSpell),
+    new(s: &str) => CanSpell::new(s),
+    |v| v
+);
+py_wrapper!(
+    PyAnagramOf("AnagramOf", AnagramOf),
+    new(s: &str) => AnagramOf::new(s),
+    |v| v
+);
+py_wrapper!(
+    PyBackwards("Backwards", String),
+    new(s: String) => s,
+    |v| Backwards(v)
+);
+py_wrapper!(
+    PyAtomicNumber("AtomicNumber", String),
+    new(s: String) => s,
+    |v| AtomicNumber(v)
+);
+
+/// `DivisibleBy` accepts both Python `int` and `float`.
+#[derive(Debug, Clone, Copy, FromPyObject)]
+enum Dividend {
+    Int(i128),
+    Float(f64),
+}
+
+#[pyclass(name = "DivisibleBy", frozen)]
+struct PyDivisibleBy(Dividend);
+
+#[pymethods]
+impl PyDivisibleBy {
+    #[new]
+    fn new(value: Dividend) -> Self {
+        Self(value)
+    }
+
+    fn thirteen(&self) -> bool {
+        match self.0 {
+            Dividend::Int(n) => DivisibleBy(n).thirteen(),
+            Dividend::Float(n) => DivisibleBy(n).thirteen(),
+        }
+    }
+
+    fn __bool__(&self) -> bool {
+        self.thirteen()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            Dividend::Int(n) => format!("{:?}", DivisibleBy(n)),
+            Dividend::Float(n) => format!("{:?}", DivisibleBy(n)),
+        }
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "is_thirteen")]
+fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_is_thirteen, m)?)?;
+    m.add_function(wrap_pyfunction!(is_thirteen_many, m)?)?;
+    m.add_class::<PyRoughly>()?;
+    m.add_class::<PyWithin>()?;
+    m.add_class::<PyDivisibleBy>()?;
+    m.add_class::<PyCanSpell>()?;
+    m.add_class::<PyAnagramOf>()?;
+    m.add_class::<PyBackwards>()?;
+    m.add_class::<PyAtomicNumber>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "python_test.rs"]
+mod python_test;
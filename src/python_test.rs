@@ -0,0 +1,73 @@
+use super::*;
+use rstest::rstest;
+use std::ffi::CString;
+
+/// Evaluates `expression` with the module's contents in scope.
+fn eval<T>(expression: &str, f: impl FnOnce(PyResult<Bound<'_, PyAny>>) -> T) -> T {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let module = PyModule::new(py, "is_thirteen").unwrap();
+        python_module(&module).unwrap();
+        let code = CString::new(expression).unwrap();
+        f(py.eval(&code, Some(&module.dict()), None))
+    })
+}
+
+#[rstest]
+#[case("is_thirteen(13)", true)]
+#[case("is_thirteen(14)", false)]
+#[case("is_thirteen(2 ** 200)", false)]
+#[case("is_thirteen(13.0)", true)]
+#[case("is_thirteen('13')", true)]
+#[case("is_thirteen('Thirteen')", true)]
+#[case("is_thirteen('十三')", true)]
+#[case("is_thirteen(b'xiii')", true)]
+#[case("is_thirteen(b'\\xff')", false)]
+#[case("is_thirteen(13 + 0j)", true)]
+#[case("is_thirteen(13j)", true)]
+#[case("is_thirteen(13 + 13j)", true)]
+#[case("is_thirteen(13 + 1j)", false)]
+#[case("is_thirteen(True)", false)]
+#[case("is_thirteen(None)", false)]
+#[case(
+    "is_thirteen_many([13, '13', 14.0, 'B']) == [True, True, False, True]",
+    true
+)]
+#[case(
+    "is_thirteen_many(x for x in range(12, 15)) == [False, True, False]",
+    true
+)]
+#[case("Roughly(12.5).thirteen()", true)]
+#[case("Roughly(13.5).thirteen()", false)]
+#[case("bool(Within(12.0, 1.0))", true)]
+#[case("Within(11.0, 1.0).thirteen()", false)]
+#[case("DivisibleBy(26).thirteen()", true)]
+#[case("DivisibleBy(13.0).thirteen()", true)]
+#[case("DivisibleBy(1).thirteen()", false)]
+#[case("CanSpell('eihbtrtAecdn').thirteen()", true)]
+#[case("AnagramOf('nRteehit').thirteen()", true)]
+#[case("Backwards('neetRiht').thirteen()", true)]
+#[case("AtomicNumber('Aluminum').thirteen()", true)]
+#[case("AtomicNumber('').thirteen()", false)]
+fn test_module(#[case] expression: &str, #[case] expected: bool) {
+    eval(expression, |result| {
+        assert_eq!(result.unwrap().extract::<bool>().unwrap(), expected)
+    });
+}
+
+#[test]
+fn unsupported_type() {
+    eval("is_thirteen([13])", |result| {
+        assert!(result.unwrap_err().to_string().starts_with("TypeError"))
+    });
+}
+
+#[test]
+fn repr() {
+    eval("repr(Within(12.0, 1.0))", |result| {
+        assert_eq!(
+            result.unwrap().extract::<String>().unwrap(),
+            "Within { value: 12.0, radius: 1.0 }"
+        )
+    });
+}
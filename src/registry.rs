@@ -0,0 +1,211 @@
+//! A set of thirteen strings that starts out as [`THIRTEEN_STRINGS`] and can be changed at
+//! runtime. Build a [`Matcher`] from it to check strings against it.
+//!
+//! ```
+//! # #[cfg(feature = "numerals")] {
+//! use is_thirteen::matcher::Matcher;
+//! use is_thirteen::registry::ThirteenRegistry;
+//!
+//! let mut registry = ThirteenRegistry::new();
+//! registry.insert("Project Falcon");
+//! registry.remove("PT");
+//!
+//! let matcher = Matcher::from(registry);
+//! assert!(matcher.is_match("project falcon"));
+//! assert!(!matcher.is_match("pt"));
+//! assert!(matcher.is_match("XIII"));
+//! # }
+//! ```
+//!
+//! [`Matcher`]: crate::matcher::Matcher
+
+use crate::fold_case;
+use crate::thirteen_strings::{Categories, ThirteenStrings, THIRTEEN_STRINGS};
+use fnv::FnvHashSet as HashSet;
+use std::borrow::Cow;
+#[cfg(feature = "registry-files")]
+use std::fmt;
+#[cfg(feature = "registry-files")]
+use std::path::{Path, PathBuf};
+
+/// Thirteen strings, stored in the lowercase form that `&str::thirteen` compares against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThirteenRegistry {
+    entries: HashSet<Cow<'static, str>>,
+}
+
+impl ThirteenRegistry {
+    /// Returns a registry containing [`THIRTEEN_STRINGS`].
+    pub fn new() -> Self {
+        Self::from_strings(THIRTEEN_STRINGS)
+    }
+
+    /// Returns a registry containing the built-in strings in `categories`.
+    pub fn with_categories(categories: Categories) -> Self {
+        Self::from_strings(ThirteenStrings::new(categories))
+    }
+
+    fn from_strings(strings: ThirteenStrings) -> Self {
+        Self {
+            entries: strings.iter().map(|&s| Cow::Borrowed(s)).collect(),
+        }
+    }
+
+    /// Returns a registry without any entries.
+    pub fn empty() -> Self {
+        Self {
+            entries: HashSet::default(),
+        }
+    }
+
+    /// Adds `entry`. Returns `false` if it was already present.
+    pub fn insert(&mut self, entry: &str) -> bool {
+        let entry = folded(entry);
+        if self.entries.contains(entry.as_str()) {
+            false
+        } else {
+            self.entries.insert(Cow::Owned(entry))
+        }
+    }
+
+    /// Removes `entry`. Returns `false` if it was not present.
+    pub fn remove(&mut self, entry: &str) -> bool {
+        self.entries.remove(folded(entry).as_str())
+    }
+
+    /// Returns `true` if the lowercase version of `s` is an entry.
+    pub fn contains(&self, s: &str) -> bool {
+        self.entries.contains(folded(s).as_str())
+    }
+
+    /// Returns the entry equal to `lowercase`, which must already be folded with `fold_case()`.
+    pub(crate) fn get(&self, lowercase: &str) -> Option<Cow<'static, str>> {
+        self.entries.get(lowercase).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the entries in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.as_ref())
+    }
+
+    /// Applies an allow/deny list. Entries in `allow` are added, then entries in `deny` are
+    /// removed, so an entry in both lists ends up removed.
+    pub fn apply(&mut self, list: &AllowDenyList) {
+        for entry in &list.allow {
+            self.insert(entry);
+        }
+        for entry in &list.deny {
+            self.remove(entry);
+        }
+    }
+
+    /// Applies the allow/deny list in a `.toml` or `.json` file. See [`AllowDenyList`] for the
+    /// format.
+    #[cfg(feature = "registry-files")]
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), LoadError> {
+        let path = path.as_ref();
+        let error = |kind| LoadError {
+            path: path.to_path_buf(),
+            kind,
+        };
+        let text = std::fs::read_to_string(path).map_err(|e| error(LoadErrorKind::Io(e)))?;
+        let list = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&text).map_err(|e| error(LoadErrorKind::Toml(e)))?,
+            Some("json") => {
+                serde_json::from_str(&text).map_err(|e| error(LoadErrorKind::Json(e)))?
+            }
+            _ => return Err(error(LoadErrorKind::UnknownFormat)),
+        };
+        self.apply(&list);
+        Ok(())
+    }
+}
+
+impl Default for ThirteenRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for ThirteenRegistry {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, entries: I) {
+        for entry in entries {
+            self.insert(entry.as_ref());
+        }
+    }
+}
+
+fn folded(s: &str) -> String {
+    let mut lowercase = String::new();
+    fold_case(s, &mut lowercase);
+    lowercase
+}
+
+/// An allow/deny list. In TOML:
+///
+/// ```toml
+/// allow = ["Project Falcon", "Floor 13"]
+/// deny = ["dilma", "pt"]
+/// ```
+///
+/// and in JSON:
+///
+/// ```json
+/// {"allow": ["Project Falcon", "Floor 13"], "deny": ["dilma", "pt"]}
+/// ```
+///
+/// Both keys are optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "registry-files", derive(serde::Deserialize))]
+#[cfg_attr(feature = "registry-files", serde(default, deny_unknown_fields))]
+pub struct AllowDenyList {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+/// Returned by [`ThirteenRegistry::load`].
+#[cfg(feature = "registry-files")]
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: PathBuf,
+    pub kind: LoadErrorKind,
+}
+
+#[cfg(feature = "registry-files")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LoadErrorKind {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    /// The file extension is not `.toml` or `.json`.
+    UnknownFormat,
+}
+
+#[cfg(feature = "registry-files")]
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path.display())?;
+        match &self.kind {
+            LoadErrorKind::Io(e) => e.fmt(f),
+            LoadErrorKind::Toml(e) => e.fmt(f),
+            LoadErrorKind::Json(e) => e.fmt(f),
+            LoadErrorKind::UnknownFormat => f.write_str("expected a .toml or .json file"),
+        }
+    }
+}
+
+#[cfg(feature = "registry-files")]
+impl std::error::Error for LoadError {}
+
+#[cfg(test)]
+#[path = "registry_test.rs"]
+mod registry_test;
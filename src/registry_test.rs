@@ -0,0 +1,96 @@
+use super::*;
+#[cfg(any(feature = "languages", feature = "registry-files"))]
+use rstest::rstest;
+
+#[test]
+fn builtin() {
+    let registry = ThirteenRegistry::new();
+    assert_eq!(registry.len(), THIRTEEN_STRINGS.len());
+    assert!(registry
+        .iter()
+        .all(|entry| THIRTEEN_STRINGS.contains(entry)));
+    assert_eq!(registry, ThirteenRegistry::default());
+    assert!(ThirteenRegistry::empty().is_empty());
+}
+
+#[cfg(all(feature = "numerals", feature = "politics"))]
+#[test]
+fn with_categories() {
+    let registry = ThirteenRegistry::with_categories(Categories::all() - Categories::POLITICS);
+    assert!(!registry.contains("PT"));
+    assert!(registry.contains("XIII"));
+    assert_eq!(
+        registry.len(),
+        THIRTEEN_STRINGS.len() - ThirteenStrings::new(Categories::POLITICS).len()
+    );
+}
+
+#[cfg(feature = "languages")]
+#[rstest]
+#[case("Thirteen")]
+#[case("THIRTEEN")]
+#[case("ΔΕΚΑΤΡΊΑ")]
+fn contains_folds_case(#[case] s: &str) {
+    assert!(ThirteenRegistry::new().contains(s));
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut registry = ThirteenRegistry::empty();
+    assert!(registry.insert("Project Falcon"));
+    assert!(!registry.insert("PROJECT FALCON"));
+    assert!(registry.contains("project falcon"));
+    assert_eq!(registry.iter().collect::<Vec<_>>(), ["project falcon"]);
+
+    assert!(registry.remove("Project FALCON"));
+    assert!(!registry.remove("project falcon"));
+    assert!(registry.is_empty());
+}
+
+#[cfg(feature = "politics")]
+#[test]
+fn remove_builtin() {
+    let mut registry = ThirteenRegistry::new();
+    assert!(registry.remove("Dilma"));
+    assert!(!registry.contains("dilma"));
+}
+
+#[test]
+fn apply() {
+    let mut registry = ThirteenRegistry::new();
+    registry.apply(&AllowDenyList {
+        allow: vec!["Floor 13".to_string(), "both".to_string()],
+        deny: vec!["PT".to_string(), "both".to_string()],
+    });
+    assert!(registry.contains("floor 13"));
+    assert!(!registry.contains("pt"));
+    assert!(!registry.contains("both"));
+}
+
+#[cfg(all(feature = "registry-files", feature = "languages"))]
+#[rstest]
+#[case("tests/registry/list.toml")]
+#[case("tests/registry/list.json")]
+fn load(#[case] path: &str) {
+    let mut registry = ThirteenRegistry::new();
+    registry
+        .load(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path))
+        .unwrap();
+    assert!(registry.contains("Project Falcon"));
+    assert!(registry.contains("floor 13"));
+    assert!(!registry.contains("dilma"));
+    assert!(!registry.contains("pt"));
+    assert!(registry.contains("thirteen"));
+}
+
+#[cfg(feature = "registry-files")]
+#[rstest]
+#[case("tests/registry/missing.toml")]
+#[case("tests/registry/invalid.toml")]
+#[case("README.md")]
+fn load_error(#[case] path: &str) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+    let error = ThirteenRegistry::new().load(&path).unwrap_err();
+    assert_eq!(error.path, path);
+    assert!(error.to_string().starts_with(&path.display().to_string()));
+}
@@ -0,0 +1,151 @@
+use crate::IsThirteen;
+use std::ops::Range;
+
+/// The kind of literal that a [`Found`] thirteen is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LiteralKind {
+    /// A numeric literal that equals 13, such as `13`, `0xd` or `13.0f32`.
+    Number,
+    /// A quoted string whose contents are a thirteen string.
+    String,
+}
+
+/// A thirteen found in source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found {
+    /// Byte range of the whole literal, including quotes and suffixes.
+    pub range: Range<usize>,
+    pub kind: LiteralKind,
+}
+
+/// Finds every thirteen literal in `text`. Strings are delimited by `"`, `'` or `` ` `` and may
+/// contain backslash escapes. Numbers may use `0x`, `0o` and `0b` prefixes, `_` separators and
+/// type suffixes.
+pub fn scan(text: &str) -> Scanner<'_> {
+    Scanner { text, pos: 0 }
+}
+
+/// Iterator returned by [`scan`].
+#[derive(Debug, Clone)]
+pub struct Scanner<'t> {
+    text: &'t str,
+    pos: usize,
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = Found;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.text[self.pos..].chars().next() {
+            let start = self.pos;
+            match c {
+                '"' | '\'' | '`' => {
+                    if let Some((end, contents)) = string_literal(self.text, start, c) {
+                        self.pos = end;
+                        if contents.as_str().thirteen() {
+                            return Some(Found {
+                                range: start..end,
+                                kind: LiteralKind::String,
+                            });
+                        }
+                        continue;
+                    }
+                }
+                '0'..='9' if !follows_word(self.text, start) => {
+                    let end = number_literal_end(self.text, start);
+                    self.pos = end;
+                    if number_literal_value(&self.text[start..end]).is_some_and(|n| n.thirteen()) {
+                        return Some(Found {
+                            range: start..end,
+                            kind: LiteralKind::Number,
+                        });
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        None
+    }
+}
+
+/// Returns `true` if the character before `pos` makes a digit at `pos` part of an identifier or
+/// of another number.
+fn follows_word(text: &str, pos: usize) -> bool {
+    text[..pos]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Returns the end of the string literal that starts with `quote` at `start`, and its unescaped
+/// contents. Only backtick strings may span lines.
+fn string_literal(text: &str, start: usize, quote: char) -> Option<(usize, String)> {
+    let mut contents = String::new();
+    let mut chars = text[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, 'n')) => contents.push('\n'),
+                Some((_, 't')) => contents.push('\t'),
+                Some((_, escaped)) => contents.push(escaped),
+                None => return None,
+            },
+            '\n' if quote != '`' => return None,
+            _ if c == quote => return Some((start + 1 + i + c.len_utf8(), contents)),
+            _ => contents.push(c),
+        }
+    }
+    None
+}
+
+fn number_literal_end(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+    let is_hex = bytes[start..].len() > 1 && matches!(bytes[start + 1], b'x' | b'X');
+    let mut end = start;
+    while end < bytes.len() {
+        let b = bytes[end];
+        let part_of_literal = b.is_ascii_alphanumeric()
+            || b == b'_'
+            || (b == b'.' && bytes.get(end + 1).is_some_and(u8::is_ascii_digit))
+            || (matches!(b, b'+' | b'-') && !is_hex && matches!(bytes[end - 1], b'e' | b'E'));
+        if !part_of_literal {
+            break;
+        }
+        end += 1;
+    }
+    end
+}
+
+/// Parses a numeric literal such as `13`, `1_3`, `0xd`, `0b1101`, `13u8` or `1.3e1`.
+fn number_literal_value(literal: &str) -> Option<f64> {
+    let literal = literal.replace('_', "");
+    let radix = match literal.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        return u128::from_str_radix(&literal[2..], radix)
+            .ok()
+            .map(|n| n as f64);
+    }
+
+    // Strip type suffixes like u8, f32 or L
+    let digits_end = literal
+        .char_indices()
+        .find(|&(i, c)| {
+            c.is_ascii_alphabetic()
+                && !(matches!(c, 'e' | 'E')
+                    && literal[i + 1..]
+                        .starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-'))
+        })
+        .map_or(literal.len(), |(i, _)| i);
+    literal[..digits_end].parse().ok()
+}
+
+#[cfg(test)]
+#[path = "scanner_test.rs"]
+mod scanner_test;
@@ -0,0 +1,55 @@
+use super::*;
+use rstest::rstest;
+
+#[rstest]
+#[case("", vec![])]
+#[case("13", vec![(0..2, LiteralKind::Number)])]
+#[case("let x = 13;", vec![(8..10, LiteralKind::Number)])]
+#[case("x = 13u8 + 0xd + 0b1101 + 0o15", vec![
+    (4..8, LiteralKind::Number),
+    (11..14, LiteralKind::Number),
+    (17..23, LiteralKind::Number),
+    (26..30, LiteralKind::Number),
+])]
+#[case("13.0 1_3 1.3e1 130e-1", vec![
+    (0..4, LiteralKind::Number),
+    (5..8, LiteralKind::Number),
+    (9..14, LiteralKind::Number),
+    (15..21, LiteralKind::Number),
+])]
+#[case("x13 113 131 1.13 v13 14 12.0", vec![])]
+#[cfg_attr(feature = "languages", case(r#"s = "thirteen""#, vec![(4..14, LiteralKind::String)]))]
+#[cfg_attr(
+    feature = "numerals",
+    case(r#"['xiii', `XIII`, "14"]"#, vec![
+        (1..7, LiteralKind::String),
+        (9..15, LiteralKind::String),
+    ])
+)]
+#[cfg_attr(feature = "languages", case(r#""baker\'s dozen""#, vec![(0..16, LiteralKind::String)]))]
+#[case(r#""13" + 13"#, vec![(0..4, LiteralKind::String), (7..9, LiteralKind::Number)])]
+#[cfg_attr(feature = "languages", case("\"十三\"", vec![(0..8, LiteralKind::String)]))]
+#[case("\"unterminated 13", vec![(14..16, LiteralKind::Number)])]
+#[case("\"1\n3\"", vec![])]
+#[case("\"a 13 in a string\"", vec![])]
+fn test_scan(#[case] text: &str, #[case] expected: Vec<(Range<usize>, LiteralKind)>) {
+    let found: Vec<(Range<usize>, LiteralKind)> =
+        scan(text).map(|found| (found.range, found.kind)).collect();
+    assert_eq!(found, expected);
+}
+
+#[rstest]
+#[case("13", Some(13.0))]
+#[case("1_3", Some(13.0))]
+#[case("13i64", Some(13.0))]
+#[case("13.0f32", Some(13.0))]
+#[case("13L", Some(13.0))]
+#[case("1.3E+1", Some(13.0))]
+#[case("0XD", Some(13.0))]
+#[case("0b1101", Some(13.0))]
+#[case("0o15", Some(13.0))]
+#[case("0xz", None)]
+#[case("e", None)]
+fn test_number_literal_value(#[case] literal: &str, #[case] expected: Option<f64>) {
+    assert_eq!(number_literal_value(literal), expected);
+}
@@ -0,0 +1,271 @@
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use crate::IsThirteen;
+use serde_json::Value;
+use std::error::Error;
+use std::io::Read;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tiny_http::{Header, Method, Request};
+
+/// Address used by `is-thirteen serve` when `--bind` is not given.
+pub const DEFAULT_BIND: &str = "127.0.0.1:1313";
+
+/// The largest request body the server reads, in bytes. Larger bodies get a 413.
+const MAX_BODY_LEN: u64 = 64 * 1024;
+
+/// An HTTP server that answers thirteen questions for services that are not written in Rust.
+///
+/// | Request | Response |
+/// |-|-|
+/// | `GET /check?value=13` | `{"thirteen":true,"value":"13"}` |
+/// | `POST /check` with `[13, "xiii", 14]` | `[true,true,false]` |
+/// | `GET /entries?q=tre` | JSON array of matching [`THIRTEEN_STRINGS`] |
+/// | `GET /health` | `ok` |
+/// | `GET /metrics` | Prometheus text exposition |
+pub struct Server {
+    http: tiny_http::Server,
+    metrics: Metrics,
+}
+
+impl Server {
+    /// Binds to `addr`. Use port 0 to let the OS pick a free port.
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            http: tiny_http::Server::http(addr)?,
+            metrics: Metrics::default(),
+        })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.http.server_addr().to_ip()
+    }
+
+    /// Serves requests until [`Server::stop`] is called.
+    pub fn run(&self) {
+        for request in self.http.incoming_requests() {
+            // A client hanging up early is not our problem
+            let _ = self.handle(request);
+        }
+    }
+
+    /// Makes [`Server::run`] return.
+    pub fn stop(&self) {
+        self.http.unblock();
+    }
+
+    fn handle(&self, mut request: Request) -> std::io::Result<()> {
+        let mut body = Vec::new();
+        if *request.method() == Method::Post {
+            Read::take(request.as_reader(), MAX_BODY_LEN + 1).read_to_end(&mut body)?;
+        }
+
+        let reply = if body.len() as u64 > MAX_BODY_LEN {
+            Reply::error(413, "request body too large")
+        } else {
+            self.route(
+                request.method(),
+                request.url(),
+                &String::from_utf8_lossy(&body),
+            )
+        };
+        let content_type = Header::from_bytes(&b"Content-Type"[..], reply.content_type).unwrap();
+        request.respond(
+            tiny_http::Response::from_string(reply.body)
+                .with_status_code(reply.status)
+                .with_header(content_type),
+        )
+    }
+
+    fn route(&self, method: &Method, url: &str, body: &str) -> Reply {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        match (method, path) {
+            (Method::Get, "/check") => {
+                self.metrics.requests.check.fetch_add(1, Ordering::Relaxed);
+                match query_param(query, "value") {
+                    Some(value) => {
+                        let thirteen = value_thirteen(&value);
+                        self.metrics.record(thirteen);
+                        Reply::json(
+                            200,
+                            serde_json::json!({ "value": value, "thirteen": thirteen }),
+                        )
+                    }
+                    None => Reply::error(400, "missing query parameter: value"),
+                }
+            }
+            (Method::Post, "/check") => {
+                self.metrics.requests.batch.fetch_add(1, Ordering::Relaxed);
+                match serde_json::from_str::<Vec<Value>>(body) {
+                    Ok(values) => {
+                        let verdicts: Vec<bool> = values.iter().map(json_thirteen).collect();
+                        for &thirteen in &verdicts {
+                            self.metrics.record(thirteen);
+                        }
+                        Reply::json(200, verdicts.into())
+                    }
+                    Err(e) => Reply::error(400, &format!("expected a JSON array: {}", e)),
+                }
+            }
+            (Method::Get, "/entries") => {
+                self.metrics
+                    .requests
+                    .entries
+                    .fetch_add(1, Ordering::Relaxed);
+                let q = query_param(query, "q").unwrap_or_default().to_lowercase();
+                let mut entries: Vec<&str> = THIRTEEN_STRINGS
+                    .iter()
+                    .copied()
+                    .filter(|s| s.contains(q.as_str()))
+                    .collect();
+                entries.sort_unstable();
+                Reply::json(200, entries.into())
+            }
+            (Method::Get, "/health") => Reply {
+                status: 200,
+                content_type: b"text/plain; charset=utf-8",
+                body: "ok".to_string(),
+            },
+            (Method::Get, "/metrics") => Reply {
+                status: 200,
+                content_type: b"text/plain; version=0.0.4",
+                body: self.metrics.render(),
+            },
+            (_, "/check" | "/entries" | "/health" | "/metrics") => {
+                Reply::error(405, "method not allowed")
+            }
+            _ => Reply::error(404, "not found"),
+        }
+    }
+}
+
+struct Reply {
+    status: u16,
+    content_type: &'static [u8],
+    body: String,
+}
+
+impl Reply {
+    fn json(status: u16, value: Value) -> Self {
+        Self {
+            status,
+            content_type: b"application/json",
+            body: value.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, serde_json::json!({ "error": message }))
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    requests: RequestCounts,
+    thirteen: AtomicU64,
+    not_thirteen: AtomicU64,
+}
+
+#[derive(Default)]
+struct RequestCounts {
+    check: AtomicU64,
+    batch: AtomicU64,
+    entries: AtomicU64,
+}
+
+impl Metrics {
+    fn record(&self, thirteen: bool) {
+        let counter = if thirteen {
+            &self.thirteen
+        } else {
+            &self.not_thirteen
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn render(&self) -> String {
+        let load = |n: &AtomicU64| n.load(Ordering::Relaxed);
+        format!(
+            "# HELP is_thirteen_requests_total Requests served per endpoint.\n\
+             # TYPE is_thirteen_requests_total counter\n\
+             is_thirteen_requests_total{{endpoint=\"check\"}} {}\n\
+             is_thirteen_requests_total{{endpoint=\"check_batch\"}} {}\n\
+             is_thirteen_requests_total{{endpoint=\"entries\"}} {}\n\
+             # HELP is_thirteen_verdicts_total Values checked, by verdict.\n\
+             # TYPE is_thirteen_verdicts_total counter\n\
+             is_thirteen_verdicts_total{{thirteen=\"true\"}} {}\n\
+             is_thirteen_verdicts_total{{thirteen=\"false\"}} {}\n",
+            load(&self.requests.check),
+            load(&self.requests.batch),
+            load(&self.requests.entries),
+            load(&self.thirteen),
+            load(&self.not_thirteen),
+        )
+    }
+}
+
+/// Query values are always strings, so a value is thirteen if it is a thirteen string or if it
+/// parses as a number that is thirteen.
+fn value_thirteen(value: &str) -> bool {
+    value.thirteen() || value.parse::<f64>().is_ok_and(|n| n.thirteen())
+}
+
+/// JSON values are checked according to their JSON type.
+fn json_thirteen(value: &Value) -> bool {
+    match value {
+        Value::Null => ().thirteen(),
+        Value::Bool(b) => b.thirteen(),
+        Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => i.thirteen(),
+            (_, Some(u), _) => u.thirteen(),
+            (_, _, Some(f)) => f.thirteen(),
+            _ => false,
+        },
+        Value::String(s) => s.thirteen(),
+        Value::Array(_) | Value::Object(_) => false,
+    }
+}
+
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('=').or(Some((pair, ""))))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| percent_decode(value))
+}
+
+fn percent_decode(s: &str) -> String {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [iter.next(), iter.next()];
+                match hex {
+                    [Some(hi), Some(lo)] if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                        bytes.push(hex_value(hi) << 4 | hex_value(lo));
+                    }
+                    _ => {
+                        bytes.push(b'%');
+                        bytes.extend(hex.iter().flatten());
+                    }
+                }
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Returns the value of the hex digit `b`.
+fn hex_value(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        _ => b.to_ascii_lowercase() - b'a' + 10,
+    }
+}
+
+#[cfg(test)]
+#[path = "server_test.rs"]
+mod server_test;
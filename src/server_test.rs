@@ -0,0 +1,187 @@
+use super::*;
+use rstest::rstest;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::thread;
+
+#[rstest]
+#[case("13", true)]
+#[case("13.0", true)]
+#[case("xiii", true)]
+#[case("Thirteen", true)]
+#[case("14", false)]
+#[case("", false)]
+fn test_value_thirteen(#[case] value: &str, #[case] expected: bool) {
+    assert_eq!(value_thirteen(value), expected);
+}
+
+#[rstest]
+#[case("13", true)]
+#[case("13.0", true)]
+#[case("\"13\"", true)]
+#[case("\"baker's dozen\"", true)]
+#[case("14", false)]
+#[case("-13", false)]
+#[case("18446744073709551615", false)]
+#[case("true", false)]
+#[case("null", false)]
+#[case("[13]", false)]
+fn test_json_thirteen(#[case] json: &str, #[case] expected: bool) {
+    assert_eq!(
+        json_thirteen(&serde_json::from_str(json).unwrap()),
+        expected
+    );
+}
+
+#[rstest]
+#[case("value=13", "value", Some("13"))]
+#[case("a=1&value=baker%27s+dozen", "value", Some("baker's dozen"))]
+#[case("value=%E5%8D%81%E4%B8%89", "value", Some("十三"))]
+#[case("value=100%", "value", Some("100%"))]
+#[case("value=%zz", "value", Some("%zz"))]
+#[case("value=%+d", "value", Some("%+d"))]
+#[case("value=%-1", "value", Some("%-1"))]
+#[case("value", "value", Some(""))]
+#[case("a=1", "value", None)]
+fn test_query_param(#[case] query: &str, #[case] name: &str, #[case] expected: Option<&str>) {
+    assert_eq!(query_param(query, name).as_deref(), expected);
+}
+
+fn test_server() -> Server {
+    Server::bind("127.0.0.1:0").unwrap()
+}
+
+#[rstest]
+#[case(
+    Method::Get,
+    "/check?value=13",
+    "",
+    200,
+    r#"{"thirteen":true,"value":"13"}"#
+)]
+#[case(
+    Method::Get,
+    "/check?value=12",
+    "",
+    200,
+    r#"{"thirteen":false,"value":"12"}"#
+)]
+#[case(
+    Method::Get,
+    "/check",
+    "",
+    400,
+    r#"{"error":"missing query parameter: value"}"#
+)]
+#[case(
+    Method::Post,
+    "/check",
+    r#"[13, "XIII", 14.0, null]"#,
+    200,
+    "[true,true,false,false]"
+)]
+#[case(Method::Post, "/check", "[]", 200, "[]")]
+#[case(Method::Get, "/entries?q=tretton", "", 200, r#"["tretton"]"#)]
+#[case(Method::Get, "/entries?q=DREIZEHN", "", 200, r#"["dreizehn"]"#)]
+#[case(Method::Get, "/health", "", 200, "ok")]
+#[case(Method::Delete, "/check", "", 405, r#"{"error":"method not allowed"}"#)]
+#[case(Method::Get, "/thirteen", "", 404, r#"{"error":"not found"}"#)]
+fn test_route(
+    #[case] method: Method,
+    #[case] url: &str,
+    #[case] body: &str,
+    #[case] status: u16,
+    #[case] expected: &str,
+) {
+    let reply = test_server().route(&method, url, body);
+    assert_eq!(reply.status, status);
+    assert_eq!(reply.body, expected);
+}
+
+#[test]
+fn route_batch_invalid() {
+    let reply = test_server().route(&Method::Post, "/check", "{\"value\": 13}");
+    assert_eq!(reply.status, 400);
+}
+
+#[test]
+fn route_entries_all() {
+    let reply = test_server().route(&Method::Get, "/entries", "");
+    let entries: Vec<String> = serde_json::from_str(&reply.body).unwrap();
+    assert_eq!(entries.len(), THIRTEEN_STRINGS.len());
+}
+
+#[test]
+fn metrics() {
+    let server = test_server();
+    server.route(&Method::Get, "/check?value=13", "");
+    server.route(&Method::Post, "/check", "[13, 14, 15]");
+    let reply = server.route(&Method::Get, "/metrics", "");
+    assert!(reply
+        .body
+        .contains("is_thirteen_requests_total{endpoint=\"check\"} 1\n"));
+    assert!(reply
+        .body
+        .contains("is_thirteen_requests_total{endpoint=\"check_batch\"} 1\n"));
+    assert!(reply
+        .body
+        .contains("is_thirteen_verdicts_total{thirteen=\"true\"} 2\n"));
+    assert!(reply
+        .body
+        .contains("is_thirteen_verdicts_total{thirteen=\"false\"} 2\n"));
+}
+
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn serve_localhost() {
+    let server = Arc::new(test_server());
+    let addr = server.local_addr().unwrap();
+    let handle = {
+        let server = server.clone();
+        thread::spawn(move || server.run())
+    };
+
+    let response = send(
+        addr,
+        "GET /check?value=thirteen HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+    );
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.contains("Content-Type: application/json"));
+    assert!(response.ends_with(r#"{"thirteen":true,"value":"thirteen"}"#));
+
+    let body = r#"["13", 13, "14"]"#;
+    let response = send(
+        addr,
+        &format!(
+            "POST /check HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.ends_with("[true,true,false]"));
+
+    let body = format!("[{}]", "13,".repeat(MAX_BODY_LEN as usize / 3) + "13");
+    let response = send(
+        addr,
+        &format!(
+            "POST /check HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 413"));
+
+    server.stop();
+    handle.join().unwrap();
+}
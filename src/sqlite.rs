@@ -0,0 +1,176 @@
+//! SQLite functions. Register them on a [`rusqlite::Connection`] with [`register`], or build a
+//! `cdylib` with `cargo rustc --lib --features sqlite-extension --crate-type cdylib` and load it
+//! as an extension:
+//!
+//! ```sql
+//! .load ./libis_thirteen
+//!
+//! SELECT * FROM t WHERE is_thirteen(col);
+//! SELECT col, thirteen_reason(col) FROM t;
+//! SELECT entry FROM thirteen_entries WHERE entry LIKE '%teen';
+//! ```
+
+use crate::matcher::Matcher;
+use crate::IsThirteen;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::ValueRef;
+use rusqlite::vtab::{
+    eponymous_only_module, Context as VTabContext, Filters, IndexInfo, VTab, VTabConfig,
+    VTabConnection, VTabCursor,
+};
+use rusqlite::{ffi, Connection, Result};
+#[cfg(feature = "sqlite-extension")]
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+
+/// Registers `is_thirteen(x)`, `thirteen_reason(x)` and the `thirteen_entries` table on `conn`.
+pub fn register(conn: &Connection) -> Result<()> {
+    register_with(conn, Matcher::new())
+}
+
+/// [`register`], checking strings with `matcher` and listing its entries in `thirteen_entries`.
+pub fn register_with(conn: &Connection, matcher: Matcher) -> Result<()> {
+    let flags = FunctionFlags::SQLITE_UTF8
+        | FunctionFlags::SQLITE_DETERMINISTIC
+        | FunctionFlags::SQLITE_INNOCUOUS;
+    let is_thirteen = matcher.clone();
+    conn.create_scalar_function("is_thirteen", 1, flags, move |ctx| {
+        Ok(value_thirteen(&is_thirteen, ctx.get_raw(0)))
+    })?;
+    let reason = matcher.clone();
+    conn.create_scalar_function("thirteen_reason", 1, flags, move |ctx| {
+        Ok(thirteen_reason(&reason, ctx.get_raw(0)))
+    })?;
+    conn.create_module(
+        "thirteen_entries",
+        eponymous_only_module::<EntriesTab>(),
+        Some(matcher),
+    )
+}
+
+/// Compares an SQL value to thirteen. `NULL` is not thirteen, and BLOBs are compared as UTF-8
+/// text.
+fn value_thirteen(matcher: &Matcher, value: ValueRef<'_>) -> bool {
+    match value {
+        ValueRef::Null => false,
+        ValueRef::Integer(n) => n.thirteen(),
+        ValueRef::Real(n) => n.thirteen(),
+        ValueRef::Text(s) | ValueRef::Blob(s) => {
+            std::str::from_utf8(s).is_ok_and(|s| matcher.is_match(s))
+        }
+    }
+}
+
+/// Returns why the argument is thirteen as text, or `NULL` if it is not thirteen.
+fn thirteen_reason(matcher: &Matcher, value: ValueRef<'_>) -> Option<String> {
+    match value {
+        ValueRef::Null => None,
+        ValueRef::Integer(n) => n.thirteen().then(|| "integer".to_string()),
+        ValueRef::Real(n) => n.thirteen().then(|| "real".to_string()),
+        ValueRef::Text(s) | ValueRef::Blob(s) => std::str::from_utf8(s)
+            .ok()
+            .and_then(|s| matcher.explain(s))
+            .map(|reason| reason.to_string()),
+    }
+}
+
+/// Entry point for `.load` and `sqlite3_load_extension()`.
+///
+/// # Safety
+///
+/// Must only be called by SQLite.
+#[cfg(feature = "sqlite-extension")]
+#[no_mangle]
+pub unsafe extern "C" fn sqlite3_isthirteen_init(
+    db: *mut ffi::sqlite3,
+    pz_err_msg: *mut *mut c_char,
+    p_api: *mut ffi::sqlite3_api_routines,
+) -> c_int {
+    Connection::extension_init2(db, pz_err_msg, p_api, |conn| register(&conn).map(|_| false))
+}
+
+/// `thirteen_entries`, a read-only table with one `entry` column listing the entries of a
+/// [`Matcher`].
+#[repr(C)]
+struct EntriesTab {
+    /// Base class. Must be first.
+    base: ffi::sqlite3_vtab,
+    matcher: Matcher,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for EntriesTab {
+    type Aux = Matcher;
+    type Cursor = EntriesCursor<'vtab>;
+
+    fn connect(
+        db: &mut VTabConnection,
+        aux: Option<&Matcher>,
+        _args: &[&[u8]],
+    ) -> Result<(String, Self)> {
+        db.config(VTabConfig::Innocuous)?;
+        let vtab = Self {
+            base: ffi::sqlite3_vtab::default(),
+            matcher: aux.cloned().unwrap_or_default(),
+        };
+        Ok(("CREATE TABLE x(entry TEXT)".to_string(), vtab))
+    }
+
+    fn best_index(&self, info: &mut IndexInfo) -> Result<()> {
+        let rows = self.matcher.entries().len();
+        info.set_estimated_cost(rows as f64);
+        info.set_estimated_rows(rows as i64);
+        Ok(())
+    }
+
+    fn open(&'vtab mut self) -> Result<EntriesCursor<'vtab>> {
+        Ok(EntriesCursor {
+            base: ffi::sqlite3_vtab_cursor::default(),
+            matcher: &self.matcher,
+            entries: Vec::new(),
+            row: 0,
+        })
+    }
+}
+
+#[repr(C)]
+struct EntriesCursor<'vtab> {
+    /// Base class. Must be first.
+    base: ffi::sqlite3_vtab_cursor,
+    matcher: &'vtab Matcher,
+    entries: Vec<&'vtab str>,
+    row: usize,
+}
+
+unsafe impl VTabCursor for EntriesCursor<'_> {
+    fn filter(
+        &mut self,
+        _idx_num: c_int,
+        _idx_str: Option<&str>,
+        _args: &Filters<'_>,
+    ) -> Result<()> {
+        self.entries = self.matcher.entries();
+        self.row = 0;
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.row += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.row >= self.entries.len()
+    }
+
+    fn column(&self, ctx: &mut VTabContext, _i: c_int) -> Result<()> {
+        ctx.set_result(&self.entries[self.row])
+    }
+
+    fn rowid(&self) -> Result<i64> {
+        Ok(self.row as i64 + 1)
+    }
+}
+
+#[cfg(all(test, not(feature = "sqlite-extension")))]
+#[path = "sqlite_test.rs"]
+mod sqlite_test;
@@ -0,0 +1,107 @@
+use super::*;
+use crate::registry::ThirteenRegistry;
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use rstest::rstest;
+
+fn connection() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    register(&conn).unwrap();
+    conn
+}
+
+#[rstest]
+#[case("13", true)]
+#[case("13.0", true)]
+#[case("'13'", true)]
+#[case("'Thirteen'", true)]
+#[case("'十三'", true)]
+#[case("X'78696969'", true)] // "xiii"
+#[case("X'ff'", false)]
+#[case("14", false)]
+#[case("'14'", false)]
+#[case("NULL", false)]
+fn is_thirteen(#[case] value: &str, #[case] expected: bool) {
+    let sql = format!("SELECT is_thirteen({})", value);
+    let result: bool = connection().query_row(&sql, [], |row| row.get(0)).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case("13", Some("integer"))]
+#[case("13.0", Some("real"))]
+#[case("'B'", Some("literal"))]
+#[case("'Thirteen'", Some("entry \"thirteen\""))]
+#[case("'aaaaaaaaaaaaa'", Some("'a' repeated 13 times"))]
+#[case("14", None)]
+#[case("NULL", None)]
+fn test_thirteen_reason(#[case] value: &str, #[case] expected: Option<&str>) {
+    let sql = format!("SELECT thirteen_reason({})", value);
+    let result: Option<String> = connection().query_row(&sql, [], |row| row.get(0)).unwrap();
+    assert_eq!(result.as_deref(), expected);
+}
+
+#[test]
+fn filter_table() {
+    let conn = connection();
+    conn.execute_batch(
+        "CREATE TABLE t(col);
+         INSERT INTO t VALUES (13), (14), ('xiii'), ('fourteen'), (13.0), (NULL);",
+    )
+    .unwrap();
+    let mut statement = conn
+        .prepare("SELECT rowid FROM t WHERE is_thirteen(col)")
+        .unwrap();
+    let rows: Vec<i64> = statement
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(rows, vec![1, 3, 5]);
+}
+
+#[test]
+fn entries() {
+    let conn = connection();
+    let count: usize = conn
+        .query_row("SELECT count(*) FROM thirteen_entries", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(count, THIRTEEN_STRINGS.len());
+
+    let mut statement = conn
+        .prepare("SELECT entry FROM thirteen_entries() WHERE entry IN ('xiii', 'fourteen')")
+        .unwrap();
+    let entries: Vec<String> = statement
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(entries, vec!["xiii"]);
+}
+
+#[test]
+fn registry() {
+    let mut registry = ThirteenRegistry::empty();
+    registry.insert("Floor 13");
+    let conn = Connection::open_in_memory().unwrap();
+    register_with(&conn, Matcher::from(registry)).unwrap();
+
+    let row: (bool, bool, String) = conn
+        .query_row(
+            "SELECT is_thirteen('floor 13'), is_thirteen('xiii'), thirteen_reason('FLOOR 13')",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(row, (true, false, "entry \"floor 13\"".to_string()));
+
+    let entries: String = conn
+        .query_row(
+            "SELECT group_concat(entry) FROM thirteen_entries",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(entries, "floor 13");
+}
@@ -1,349 +1,152 @@
-use phf::{phf_set, Set};
-
-/// Strings that are thirteen.
-pub static THIRTEEN_STRINGS: Set<&'static str> = phf_set! {
-    "xiii", // Roman numeral 13
-    "1.3", // Basically 13, see proof in #420
-    "1️⃣3️⃣", // emoji sequence of 1 and 3
-    "https://en.wikipedia.org/wiki/this_is_thirteen", // Because it is thirteen
-    "https://scontent.cdninstagram.com/hphotos-xtf1/t51.2885-15/s320x320/e35/12237511_444845689040315_1101385461_n.jpg", // Just because we can
-    "https://www.youtube.com/watch?v=pte3jg-2ax4", // Thirteen by Big Star
-    "https://www.youtube.com/watch?v=33kv5d2zwyc", // The best Johny Cash's song
-    "remy hadley", // And because she's "Thirteen"
-    "olivia wilde", // AND because SHE's "Thirteen"
-    "baker's dozen", // Bakers gonna bake
-    "dr. remy beauregard hadley", // Why not 13's real name?!
-    "patty tsai", // 蔡依珊 is a public figure in Taiwan. Her Chinese name sounds like "13".
-    "https://s3.amazonaws.com/rapgenius/calle13.jpg", // Calle 13, famous Puerto Rican band
-    "jason fly", // XIII of The XX
-    "http://www.imdb.com/title/tt0798817/", // 13 (2010)
-    "https://www.imdb.com/title/tt2991516/", // 13/13/13 (2013)
-    "https://en.wikipedia.org/wiki/xiii_(video_game)", // Because video games are also culture
-    "dilma", //Dilma, former president of Brazil. Her number is 13: https://www.google.com/search?q=dilma+13
-    "pt", // PT is Brazilian political party represented by the number 13
-    "washington luís", // Brazil's thirteenth president
-    "millard fillmore", // Thirteenth President of the United States
-    "louis xiii", // Thirteenth king of France
-    // "https://s3.amazonaws.com/rapgenius/calle13.jpg", // Calle 13, famous latin american band (duplicate)
-
-
-    // ALL HAIL ZALGO
-    "1̵̧̨̡̢̡̧̨̪͍̮̗̯̮̲͖̥̳̲̯͔͉̬̘͍͔͙̳͚̠͓̳̪̯̣͚͍͎͇̦̗͙͕̬̭̝͕̱̺̮̼̞̤̙̹̙̘̗̘͔͎̼͙̤̝̖̝̫̝̲̼̫̙͚̗͖̳̱̳͕͙̜̖̘͎̖̭̝̖͔̠̦̜̎̀͌̈́̇͜͜͠ͅͅ3̷̧̢̡̛͖̘͎͎̥̼͙̱̜͖̩̪̼̫̭̙̓̽͆̌̀̈́͗̈͗̿̀̔̏͂́̏̅͛͒̓̐́͗̋̎̓̄͛̇͋̊̇́̅̔̇̉͌̈́̊̍͗̑̌̈͆̉͐̂́̉̓̇͛̃͑̾̌̄͐̀̔́̈̐͛̈́͛̇́̍́͊͛̐́̇͆͆́͒͑̃̾̿̏̀́͆̾̀̀̆̚̕͘͘̚͜͝͝͝͝͝͝͝͝",
-    "sharon carter", // Agent 13
-
-    "end of slavery", // Thirteenth Amendment
-
-    // Television characters
-    "jodie whittaker",  // the 13th Doctor in the BBC series, "Doctor Who"
-
-    "weedle", //#13 Pokémon
-
-    // Imaginary 13's
-    "13+0i",
-    "13 + 13i",
-    "13i",
-
-    // B just looks like 13 written closer
-    // "B", // Only case-sensitive string
-    //For cultural inclusiveness also include German variants
-    "ß",
-    // "ß", (duplicate)
-    //Also greek
-    "β",
-    // "β", //actually upper case Beta, not B (duplicate)
-    //And Chinese
-    "阝", //(Kangxi radical)
-
-
-    //Adding "l" 3, "i"3, |3 and !3 because they basically look like thirteen
-    "i3",
-    "l3",
-    "|3",
-    "!3",
-
-    //Looks like 13 (flipped horizontally) - E equal to 3
-    "ei",
-    "e1",
-    "el",
-    "e|",
-
-    // Flipped characters
-    "ɩɛ",
-    "ɛɩ",
-
-    // Password variations
-    "th1rt33n",
-    "th1rte3n",
-    "th1rteen",
-    "thirt3en",
-    "thirt33n",
-    "thirte3n",
-
-    // code variations
-    // binary
-    "00001101",
-    "0b1101",
-
-    // Octal
-    "0o15",
-
-    // Hexadecimal
-    "0xd",
-
-    // Morse
-    ".---- ...--",
-    "- .... .. .-. - . . -.",
-    // "- .... .. .-. - . . -.", (duplicate)
-
-    // Caesar shift
-    "wkluwhhq",
-    // "wkluwhhq", (duplicate)
-    // "wkluwhhq", (duplicate)
-
-    //md5 hash
-    "c51ce410c124a10e0db5e4b97fc2af39",
-    //also md5 hash but UPPERCASE
-    // "c51ce410c124a10e0db5e4b97fc2af39", (duplicate)
-
-    // hex
-    "74 68 69 72 74 65 65 6e",
-    "54 48 49 52 54 45 45 4e",
-    "31 33",
-    "74 68 69 72 74 65 65 6e 0d 0a",
-    "54 68 69 72 74 65 65 6e 0d 0a",
-    "54 48 49 52 54 45 45 4e 0d 0a 0d 0a",
-
-    // base64
-    "dghpcnrlzw4=",
-    "vghpcnrlzw4=",
-    "vehjulrfru4=",
-    "mtm=",
-
-    // Languages
-    "thirteen", // English
-    "ثلاثة عشر", // Arabic (masculine)
-    "ثلاث عشرة", // Arabic (feminine)
-
-    "تلطاشر", // Arabic Slang
-    "تلتاشر", // Arabic Slang
-    "طلتاشر", // Arabic Slang
-    "طلطاشر", // Arabic Slang
-
-    "يج", //Arabic (gematria)
-
-    "سیزده", // Persian
-    "۱۳", // Persian number
-
-    "dertien", // Afrikaans / Dutch
-    "dertiendertien", // Double Dutch
-    "seri-un-teng", // Belter creole
-    "seriunteng",
-    "serí-un-teng",
-    "seríunteng",
-    "тринадесет", // Bulgarian
-    "тринайсет", // Also Bulgarian
-    "tretze", // Catalan
-    "napulo ug tulo", // Cebuano
-    "十三", // Chinese / Japanese
-    "拾參", // Chinese (traditional, upper case)
-    "拾叁", // Chinese (simplified, upper case)
-    "拾叄", // Chinese (variant)
-    "拾参", // Chinese (variant)
-    "サーティーン", // Japanese
-    "１３", // Japanese full-width
-    "trinaest", // Croatian / Serbian (latin)
-    "tretten", // Danish / Norwegian
-    "senthi", //Dothraki
-    "þrettán", // Icelandic, following are different inflections
-    "þrettándi", // e. thirteenth
-    "þrettánda",
-    "þrettándinn", // e. the thirteenth
-    "þrettándann",
-    "þrettándanum",
-    "þrettándans",
-    "þrettándar", // e. multiple thirteenths
-    "þrettándu",
-    "þrettándum",
-    "þrettándarnir", // e. the multiple thirteenths
-    "þrettándana",
-    "þrettándunum",
-    "þrettándanna",
-    "threttan", // strings without special icelandic characters
-    "threttandi",
-    "threttanda",
-    "threttandinn",
-    "threttandann",
-    "threttandanum",
-    "threttandans",
-    "threttandar",
-    "threttandu",
-    "threttandum",
-    "threttandarnir",
-    "threttandana",
-    "threttandunum",
-    "threttandanna",// end of Icelandic
-
-    // "threttandum", // end of Icelandic (duplicate)
-    "třináct", // Czech
-
-    "kolmteist", // Estonian
-    "labintatlo", // Filipino
-    "kolmetoista", // Finnish
-    "treize", // French
-    "treizième", //French (ordinal form)
-    "dreizehn", // German
-    "ცამეტი", // Georgian
-    "δεκατρία", // Greek
-    "drizäh", // Swiss German
-    "wa’mah wej", // Klingon
-    "‘umikūmākolu", // Hawaiian
-    "שלוש עשרה", // Hebrew
-    "שלושעשרה", // Hebrew (without space)
-    "ֹשְלֹש- עֶשְֹרֵה", // Hebrew (with punctuation)
-    "שלושה עשר", // Hebrew (male form)
-    "שלושהעשר", // Hebrew (male form, without space)
-    "ֹשְלֹשָה- עָשָֹר", // Hebrew (male form, with punctuation)
-    "יג", // Hebrew (gematria)
-    "י״ג", // Hebrew (gematria - apostrophes)
-    "quainel", // Quenya
-    "mînuiug", // Sindarin
-    "dektri", // Esperanto
-    "tizenhárom", // Hungarian
-    "trí déag", // Irish
-    "tredici", // Italian
-    "ಹದಿಮೂರು", //Kannada (for thirteen)
-    "೧೩",//Kannada (for 13)
-    "sêzdeh", // Kurdish
-    "tredecim", // Latin
-    "trīspadsmit", // Latvian
-    "trylika", // Lithuanian
-    "dräizéng", // Luxembourgish
-    "тринаесет", // Macedonian
-    "tiga belas", // Malay
-    "പതിമൂന്ന്", //Malayalam
-    "तेरा", // Marathi (१३)
-    "арван", // Mongolian
-    // ".---- ...--", // Morse code (duplicate)
-    "matlactlihuan yei", // Classical Nahuatl (Aztec)
-    "mahtlactli omei", // Nahuatl variant
-    "mahtlactli ihuan yei", // Nahuatl variant
-    "irteenthay", // Pig Latin
-
-    // Beginning of some Korean variants 🇰🇷
-    "열셋", // Korean
-    "십삼", // Korean
-
-    "써틴", // Korean
-    "썰틴", // Korean
-    "떠틴", // Korean
-    "떨틴", // Korean
-
-    "씹쌈", // Korean
-    "십쌈", // Korean
-    "씹삼", // Korean
-
-    "10삼", // Korean
-    "십3", // Korean
-
-    "시입삼", // Korean
-    "시이입삼", // Korean (TODO: Anything that matches "^(십|(시이*입))(삼|(사아*암))$" is 13)
-
-    "여얼세엣", // Korean
-    "열세엣", // Korean (TODO: Also, Anything that matches "^(열|(여어*얼))(셋|(세에*엣))$" is 13)
-    // End of some Korean variants 🇰🇷
-
-    // Beginning of all Polish variants 🇵🇱
-    "trzynaście", // Polish
-    "trzynasty", // Polish
-    "trzynasta", // Polish
-    "trzynaste", // Polish
-    "trzynaści", // Polish
-    "trzynastego", // Polish
-    "trzynastej", // Polish
-    "trzynastych", // Polish
-    "trzynastemu", // Polish
-    "trzynastym", // Polish
-    "trzynastą", // Polish
-    "trzynastymi", // Polish
-    "trzynastu", // Polish
-    "trzynastek", // Polish
-    "trzynastoma", // Polish
-    "trzynaścioro", // Polish
-    "trzynastka", // Polish
-    "trzynastki", // Polish
-    "trzynastką", // Polish
-    "trzynastce", // Polish
-    "trzynastko", // Polish
-    "trzynaściorgiem", // Polish
-    "trzynaściorgu", // Polish
-    "trzynaściorga", // Polish
-    "trzynastokrotny", // Polish
-    "trzynastokrotnie", // Polish
-    "trzynastokrotną", // Polish
-    "trzynastokrotnemu", // Polish
-    "trzynastokrotnej", // Polish
-    "trzynastokrotnych", // Polish
-    "trzynastokrotność", // Polish
-    "trzynastokrotności", // Polish
-    "trzynastokrotnością", // Polish
-    // End of all Polish variants 🇵🇱
-
-    // Bangla/Bengali variants
-    "১৩", // Bengali numeral
-    "তেরো",
-    "তের",
-    "ত্রয়োদশ",
-    // end of Bangla/Bengali variants
-
-    "treze", // Portuguese
-    "ਤੇਰਾਂ", // Punjabi - thirteen
-    "੧੩", // Punjabi Numeral - 13
-    "treisprezece", // Romanian
-    "treispe", // Romanian
-    "тринадцать", // Russian (cyrillic)
-    "ⱅⱃⰺⱀⰰⰴⱌⰰⱅⱐ", // Russian (glagolitic)
-    "тринаест", // Serbian (cyrillic)
-    "trinásť", // Slovak
-    "trinajst", // Slovenian
-    "trece", // Spanish
-    "diez-y-tres", // Spanglish
-    "trese", // Tagalog
-    "on üç", // Turkish
-    // "dektri", //Speranto (duplicate)
-    "tlettax", // Maltese
-    "tretton", // Swedish
-    "பதின்மூன்று", // Tamil
-    "patiṉmūṉṟu", // Tamil
-    "สิบสาม", // Thai
-    "๑๓", // Thai Numeral
-    "sipsam", // Thai Transcription
-    "sip sam", // Thai Transcription with space
-    "тринадцять", // Ukrainian
-    "تیرہ", // Urdu
-    "tayra", // Roman Urdu
-    "mười ba", // Vietnamese
-    "tri ar ddeg", // Welsh
-    "דרייַצן", // Yiddish,
-    "דרייצן", // Yiddish (without diacritics),
-    "kumi na tatu", // Swahili
-    "तेह्र", //Nepali
-    "१३", //Devanagari
-    "तेरह", //Hindi
-    "7h1r733n", // Crypto
-    // Thirteen pronunciation
-    "θərˈtiːn",
-    "పదమూడు", //Telugu
-    "shí sān", // Pinyin (formal)
-    "shi san", // Pinyin (without tones)
-    "shísān",  // Pinyin (without spaces)
-    "shisan", // Pinyin (without spaces and tones)
-    "он үш", // Kazakh
-    "он уш", // Kazakh
-    "onúsh", // Kazakh latin,
-    "онүш", // Kazakh
-    "онуш", // Kazakh
-    // "onúsh", // Kazakh latin (duplicate)
-    "paci", // lojban
-    "ishumi nantathu", // isiZulu
-    "lishumi elinesithathu", // isiXhosa
-};
+//! The built-in thirteen strings, split into [`Categories`]. Each category is compiled in by the
+//! cargo feature of the same name, and all of them are enabled by default. The entries live in
+//! `data/thirteen_strings.toml`, which `build.rs` checks and turns into lookup tables.
+//!
+//! ```
+//! # #[cfg(all(feature = "numerals", feature = "politics"))] {
+//! use is_thirteen::thirteen_strings::{Categories, ThirteenStrings, THIRTEEN_STRINGS};
+//!
+//! assert!(THIRTEEN_STRINGS.contains("dilma"));
+//!
+//! let strings = ThirteenStrings::new(Categories::all() - Categories::POLITICS);
+//! assert!(!strings.contains("dilma"));
+//! assert_eq!(strings.category("xiii"), Some(Categories::NUMERALS));
+//! # }
+//! ```
+
+use bitflags::bitflags;
+use phf::Map;
+
+bitflags! {
+    /// Categories of thirteen strings. A category whose cargo feature is disabled has no entries.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Categories: u8 {
+        /// Numerals in other notations and scripts, like "xiii", "0xd" and "१३". Feature
+        /// `numerals`.
+        const NUMERALS = 1 << 0;
+        /// "Thirteen" in natural and constructed languages, like "treize" and "quainel". Feature
+        /// `languages`.
+        const LANGUAGES = 1 << 1;
+        /// Leetspeak and strings that look like 13, like "th1rt33n" and "ß". Feature `leetspeak`.
+        const LEETSPEAK = 1 << 2;
+        /// "13" and "thirteen" in Morse code, hexadecimal, Base64 and a Caesar cipher. Feature
+        /// `encodings`.
+        const ENCODINGS = 1 << 3;
+        /// Hashes of "13". Feature `hashes`.
+        const HASHES = 1 << 4;
+        /// Characters, actors and other references to 13 in popular culture. Feature `pop-culture`.
+        const POP_CULTURE = 1 << 5;
+        /// Politicians, parties and events associated with 13. Feature `politics`.
+        const POLITICS = 1 << 6;
+        /// URLs of pages about 13. Feature `urls`.
+        const URLS = 1 << 7;
+    }
+}
+
+impl Default for Categories {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// The entries of every category enabled at compile time.
+pub static THIRTEEN_STRINGS: ThirteenStrings = ThirteenStrings::new(Categories::all());
+
+/// The built-in thirteen strings in some categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThirteenStrings {
+    categories: Categories,
+}
+
+impl ThirteenStrings {
+    pub const fn new(categories: Categories) -> Self {
+        Self { categories }
+    }
+
+    pub fn categories(&self) -> Categories {
+        self.categories
+    }
+
+    /// Returns `true` if `s` is an entry. `s` is not case folded.
+    pub fn contains(&self, s: &str) -> bool {
+        self.get_key(s).is_some()
+    }
+
+    /// Returns the entry equal to `s`.
+    pub fn get_key(&self, s: &str) -> Option<&'static &'static str> {
+        self.sets().find_map(|(_, set)| set.get_key(s))
+    }
+
+    /// Returns the category of the entry equal to `s`.
+    pub fn category(&self, s: &str) -> Option<Categories> {
+        self.metadata(s).map(|metadata| metadata.category)
+    }
+
+    /// Returns what the dataset records about the entry equal to `s`.
+    pub fn metadata(&self, s: &str) -> Option<&'static Metadata> {
+        self.sets().find_map(|(_, set)| set.get(s))
+    }
+
+    /// Iterates over the entries in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static &'static str> {
+        self.sets().flat_map(|(_, set)| set.keys())
+    }
+
+    pub fn len(&self) -> usize {
+        self.sets().map(|(_, set)| set.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn sets(
+        &self,
+    ) -> impl Iterator<Item = &'static (Categories, &'static Map<&'static str, Metadata>)> {
+        let categories = self.categories;
+        SETS.iter()
+            .filter(move |(category, _)| categories.contains(*category))
+    }
+}
+
+impl Default for ThirteenStrings {
+    fn default() -> Self {
+        THIRTEEN_STRINGS
+    }
+}
+
+/// What the dataset records about an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    pub category: Categories,
+    /// The language the entry is written in, for entries that are words.
+    pub language: Option<&'static str>,
+    pub note: Option<&'static str>,
+}
+
+/// Every category enabled at compile time. No entry is in more than one set.
+static SETS: &[(Categories, &Map<&str, Metadata>)] = &[
+    #[cfg(feature = "numerals")]
+    (Categories::NUMERALS, &NUMERALS),
+    #[cfg(feature = "languages")]
+    (Categories::LANGUAGES, &LANGUAGES),
+    #[cfg(feature = "leetspeak")]
+    (Categories::LEETSPEAK, &LEETSPEAK),
+    #[cfg(feature = "encodings")]
+    (Categories::ENCODINGS, &ENCODINGS),
+    #[cfg(feature = "hashes")]
+    (Categories::HASHES, &HASHES),
+    #[cfg(feature = "pop-culture")]
+    (Categories::POP_CULTURE, &POP_CULTURE),
+    #[cfg(feature = "politics")]
+    (Categories::POLITICS, &POLITICS),
+    #[cfg(feature = "urls")]
+    (Categories::URLS, &URLS),
+];
+
+// The per-category maps, generated by build.rs from data/thirteen_strings.toml
+include!(concat!(env!("OUT_DIR"), "/thirteen_strings.rs"));
+
+#[cfg(test)]
+#[path = "thirteen_strings_test.rs"]
+mod thirteen_strings_test;
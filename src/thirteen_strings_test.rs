@@ -0,0 +1,72 @@
+use super::*;
+use rstest::rstest;
+use std::collections::HashSet;
+
+/// The categories whose features are enabled.
+fn enabled() -> Categories {
+    SETS.iter().map(|(category, _)| *category).collect()
+}
+
+#[test]
+fn categories_are_disjoint() {
+    let mut seen = HashSet::new();
+    for (category, set) in SETS {
+        for entry in set.keys() {
+            assert!(seen.insert(entry), "{:?} is also in {:?}", entry, category);
+        }
+    }
+    assert_eq!(seen.len(), THIRTEEN_STRINGS.len());
+}
+
+#[rstest]
+#[case("xiii", Categories::NUMERALS)]
+#[case("treize", Categories::LANGUAGES)]
+#[case("th1rt33n", Categories::LEETSPEAK)]
+#[case("mtm=", Categories::ENCODINGS)]
+#[case("c51ce410c124a10e0db5e4b97fc2af39", Categories::HASHES)]
+#[case("weedle", Categories::POP_CULTURE)]
+#[case("dilma", Categories::POLITICS)]
+#[case("http://www.imdb.com/title/tt0798817/", Categories::URLS)]
+fn category(#[case] s: &str, #[case] expected: Categories) {
+    let only = ThirteenStrings::new(expected);
+    if !enabled().contains(expected) {
+        assert!(only.is_empty());
+        assert_eq!(THIRTEEN_STRINGS.category(s), None);
+        return;
+    }
+    assert_eq!(THIRTEEN_STRINGS.category(s), Some(expected));
+
+    assert_eq!(only.get_key(s), Some(&s));
+    assert!(only
+        .iter()
+        .all(|entry| THIRTEEN_STRINGS.category(entry) == Some(expected)));
+
+    let without = ThirteenStrings::new(Categories::all() - expected);
+    assert!(!without.contains(s));
+    assert_eq!(without.len() + only.len(), THIRTEEN_STRINGS.len());
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn metadata() {
+    assert_eq!(
+        THIRTEEN_STRINGS.metadata("treizième"),
+        Some(&Metadata {
+            category: Categories::LANGUAGES,
+            language: Some("French"),
+            note: Some("ordinal form"),
+        })
+    );
+    assert_eq!(THIRTEEN_STRINGS.metadata("Treizième"), None);
+    assert!(THIRTEEN_STRINGS
+        .iter()
+        .all(|entry| THIRTEEN_STRINGS.metadata(entry).is_some()));
+}
+
+#[test]
+fn no_categories() {
+    let strings = ThirteenStrings::new(Categories::empty());
+    assert!(strings.is_empty());
+    assert_eq!(strings.iter().count(), 0);
+    assert!(!strings.contains("thirteen"));
+}
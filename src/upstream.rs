@@ -0,0 +1,587 @@
+//! Keeps the crate in sync with the JavaScript library it ports. [`parse_consts`] and [`compare`]
+//! diff upstream's `consts.js` against the built-in strings, and [`parse_tests`] translates
+//! upstream's `test.js` into the cases at the top of `src/lib_test.rs`.
+//!
+//! ```
+//! # #[cfg(all(feature = "numerals", feature = "languages"))] {
+//! use is_thirteen::thirteen_strings::THIRTEEN_STRINGS;
+//! use is_thirteen::upstream::{compare, parse_consts};
+//!
+//! let consts_js = r#"const thirteenStrings = ["XIII", "tredici", "trece!"];"#;
+//! let upstream = parse_consts(consts_js).unwrap();
+//! let comparison = compare(&upstream, &THIRTEEN_STRINGS);
+//! assert_eq!(comparison.new, ["trece!"]);
+//! assert_eq!(comparison.recased, [("XIII".to_string(), "xiii")]);
+//! # }
+//! ```
+
+use crate::thirteen_strings::ThirteenStrings;
+use crate::{builtin_entry, explain_with, fold_case, Reason};
+use fnv::FnvHashSet as HashSet;
+use std::fmt;
+
+/// The line before the generated cases in `src/lib_test.rs`.
+pub const BEGIN_GENERATED: &str =
+    "// Generated from upstream/test.js by `is-thirteen upstream tests`. Do not edit.";
+/// The line after the generated cases in `src/lib_test.rs`.
+pub const END_GENERATED: &str = "// End of generated cases";
+
+/// The year that `yearOfBirth()` assertions are translated for, so that the generated cases do
+/// not change with the clock. Upstream's suite was written against 2021.
+const REFERENCE_YEAR: i32 = 2021;
+
+/// Returns the entries of the `thirteenStrings` array in a `consts.js` file, in order and with
+/// duplicates.
+pub fn parse_consts(js: &str) -> Result<Vec<String>, ParseError> {
+    let start = match js.find("thirteenStrings") {
+        Some(start) => start,
+        None => return Err(ParseError::new(1, "no `thirteenStrings` array")),
+    };
+    let mut lexer = Lexer { js, pos: start };
+    lexer.pos += "thirteenStrings".len();
+    lexer.skip_trivia();
+    if !lexer.eat("=") && !lexer.eat(":") {
+        return Err(lexer.error("expected `=` or `:` after `thirteenStrings`"));
+    }
+    lexer.skip_trivia();
+    lexer.expect("[")?;
+
+    let mut entries = Vec::new();
+    loop {
+        lexer.skip_trivia();
+        if lexer.eat("]") {
+            return Ok(entries);
+        }
+        entries.push(lexer.string()?);
+        lexer.skip_trivia();
+        if !lexer.eat(",") {
+            lexer.skip_trivia();
+            lexer.expect("]")?;
+            return Ok(entries);
+        }
+    }
+}
+
+/// How an upstream list differs from the built-in strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Comparison {
+    /// Lowercase versions of upstream entries that are not thirteen here, in upstream order.
+    pub new: Vec<String>,
+    /// Built-in entries that upstream does not have, sorted.
+    pub missing: Vec<&'static str>,
+    /// Upstream entries that are not lowercase, with the built-in entry that they fold to.
+    pub recased: Vec<(String, &'static str)>,
+}
+
+/// Compares `upstream` entries to `strings`. Upstream entries that are thirteen because of a rule
+/// other than the dataset, like `"B"`, count as present.
+pub fn compare(upstream: &[String], strings: &ThirteenStrings) -> Comparison {
+    let mut comparison = Comparison::default();
+    let mut folded = HashSet::default();
+    let mut lowercase = String::new();
+    for entry in upstream {
+        let reason = explain_with(entry, &mut lowercase, fold_case, |lowercase| {
+            builtin_entry(strings, lowercase)
+        });
+        match reason {
+            Some(Reason::Entry(builtin)) => {
+                if *entry != builtin {
+                    let builtin = strings.get_key(&builtin).expect("entry is built in");
+                    comparison.recased.push((entry.clone(), builtin));
+                }
+            }
+            Some(_) => {}
+            None => {
+                if !comparison.new.contains(&lowercase) {
+                    comparison.new.push(lowercase.clone());
+                }
+            }
+        }
+        folded.insert(lowercase.clone());
+    }
+    comparison.missing = strings
+        .iter()
+        .copied()
+        .filter(|entry| !folded.contains(*entry))
+        .collect();
+    comparison.missing.sort_unstable();
+    comparison
+}
+
+impl Comparison {
+    /// Returns a unified diff that adds the new entries to the end of the data file `data`, which
+    /// is at `path` relative to the repository root. Their category is a guess that should be
+    /// reviewed. Returns an empty string if there are no new entries.
+    pub fn patch(&self, path: &str, data: &str) -> String {
+        if self.new.is_empty() {
+            return String::new();
+        }
+        let lines: Vec<&str> = data.lines().collect();
+        let close = lines.iter().rposition(|line| line.trim() == "]");
+        let close = close.unwrap_or(lines.len());
+        let start = close.saturating_sub(3);
+        let end = lines.len().min(close + 3);
+
+        let mut patch = format!(
+            "--- a/{}\n+++ b/{}\n@@ -{},{} +{},{} @@\n",
+            path,
+            path,
+            start + 1,
+            end - start,
+            start + 1,
+            end - start + self.new.len()
+        );
+        for line in &lines[start..close] {
+            patch.push_str(&format!(" {}\n", line));
+        }
+        for entry in &self.new {
+            patch.push_str(&format!(
+                "+    {{ value = {}, category = \"{}\", note = \"from upstream\" }},\n",
+                toml_string(entry),
+                guess_category(entry)
+            ));
+        }
+        for line in &lines[close..end] {
+            patch.push_str(&format!(" {}\n", line));
+        }
+        patch
+    }
+}
+
+fn guess_category(entry: &str) -> &'static str {
+    if entry.starts_with("http://") || entry.starts_with("https://") {
+        "urls"
+    } else {
+        "languages"
+    }
+}
+
+fn toml_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// An upstream assertion translated to Rust.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    /// A Rust expression whose value implements `IsThirteen`.
+    pub input: String,
+    /// A Rust expression that builds the same chain with the JavaScript API in `wasm`, up to but
+    /// not including `.thirteen()`. `None` if the assertion depends on the clock.
+    pub wasm_input: Option<String>,
+    pub expected: bool,
+}
+
+/// Translates the `tap.equal(is(x)...thirteen(), expected);` assertions in upstream's `test.js`.
+/// Returns an error for assertions that use parts of the API without a Rust translation.
+pub fn parse_tests(js: &str) -> Result<Vec<TestCase>, ParseError> {
+    let mut cases = Vec::new();
+    let mut pos = 0;
+    for line in js.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("tap.equal(") {
+            let mut lexer = Lexer {
+                js,
+                pos: pos + line.len() - trimmed.len(),
+            };
+            cases.push(lexer.test_case()?);
+        }
+        pos += line.len();
+    }
+    Ok(cases)
+}
+
+/// Formats `cases` as `#[case]` attributes, numbered from 1 like the upstream assertions.
+pub fn generate_cases(cases: &[TestCase]) -> String {
+    let mut generated = String::new();
+    for (i, case) in cases.iter().enumerate() {
+        let line = format!("#[case({}, {})] // {}\n", case.input, case.expected, i + 1);
+        // Attributes longer than rustfmt's limit are split the way rustfmt does it
+        if line.len() - format!(" // {}\n", i + 1).len() > 100 {
+            generated.push_str(&format!(
+                "#[case(\n    {},\n    {},\n)] // {}\n",
+                case.input,
+                case.expected,
+                i + 1
+            ));
+        } else {
+            generated.push_str(&line);
+        }
+    }
+    generated
+}
+
+/// Formats the `cases` whose input is a string as a Rust slice expression of
+/// `(number, string, expected)` tuples, for `include!` in tests that cannot use `lib_test.rs`.
+pub fn generate_string_cases(cases: &[TestCase]) -> String {
+    let mut generated = format!("{}\n&[\n", BEGIN_GENERATED);
+    for (i, case) in cases.iter().enumerate() {
+        if case.input.starts_with('"') {
+            generated.push_str(&format!(
+                "    ({}, {}, {}),\n",
+                i + 1,
+                case.input,
+                case.expected
+            ));
+        }
+    }
+    generated.push_str("]\n");
+    generated
+}
+
+/// Formats the `cases` whose input is not a string, or that chain other calls before
+/// `thirteen()`, as a Rust slice expression of `(number, fn() -> Is, expected)` tuples for the
+/// JavaScript API tests. The functions expect `is` and `JsValue` to be in scope.
+pub fn generate_chain_cases(cases: &[TestCase]) -> String {
+    let mut generated = format!("{}\n&[\n", BEGIN_GENERATED);
+    for (i, case) in cases.iter().enumerate() {
+        if let (false, Some(wasm_input)) = (case.input.starts_with('"'), &case.wasm_input) {
+            generated.push_str(&format!(
+                "    ({}, || {}, {}),\n",
+                i + 1,
+                wasm_input,
+                case.expected
+            ));
+        }
+    }
+    generated.push_str("]\n");
+    generated
+}
+
+/// Replaces the lines between [`BEGIN_GENERATED`] and [`END_GENERATED`] in `lib_test` with
+/// `generated`.
+pub fn replace_generated(lib_test: &str, generated: &str) -> Result<String, ParseError> {
+    let marker = |marker: &str| {
+        lib_test
+            .find(&format!("{}\n", marker))
+            .ok_or_else(|| ParseError::new(1, &format!("no {:?} line", marker)))
+    };
+    let begin = marker(BEGIN_GENERATED)? + BEGIN_GENERATED.len() + 1;
+    let end = marker(END_GENERATED)?;
+    if end < begin {
+        return Err(ParseError::new(
+            line_of(lib_test, end),
+            "generated cases end before they begin",
+        ));
+    }
+    Ok(format!(
+        "{}{}{}",
+        &lib_test[..begin],
+        generated,
+        &lib_test[end..]
+    ))
+}
+
+/// Returned when a JavaScript file cannot be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: &str) -> Self {
+        Self {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn line_of(s: &str, pos: usize) -> usize {
+    s[..pos].matches('\n').count() + 1
+}
+
+/// The argument of `is()`.
+enum Argument {
+    String(String),
+    /// A numeric expression, copied verbatim.
+    Number(String),
+    /// The body of `() => body`.
+    Function(String),
+}
+
+/// Reads the small part of JavaScript that upstream's files use.
+struct Lexer<'a> {
+    js: &'a str,
+    pos: usize,
+}
+
+impl Lexer<'_> {
+    fn rest(&self) -> &str {
+        &self.js[self.pos..]
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError::new(line_of(self.js, self.pos), message)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{}`", token)))
+        }
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            let comment = if trimmed.starts_with("//") {
+                trimmed.find('\n').unwrap_or(trimmed.len())
+            } else if trimmed.starts_with("/*") {
+                trimmed.find("*/").map_or(trimmed.len(), |end| end + 2)
+            } else {
+                0
+            };
+            self.pos += rest.len() - trimmed.len() + comment;
+            if comment == 0 {
+                return;
+            }
+        }
+    }
+
+    /// Reads a string literal in single, double or back quotes.
+    fn string(&mut self) -> Result<String, ParseError> {
+        let quote = match self.rest().chars().next() {
+            Some(quote @ ('\'' | '"' | '`')) => quote,
+            _ => return Err(self.error("expected a string")),
+        };
+        let mut chars = self.rest()[1..].char_indices();
+        let mut s = String::new();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                c if c == quote => {
+                    self.pos += 1 + i + 1;
+                    return Ok(s);
+                }
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'u')) => {
+                        let rest = &self.rest()[1 + i + 2..];
+                        let (hex, len) = match rest.strip_prefix('{') {
+                            Some(braced) => {
+                                let end = braced.find('}').unwrap_or(braced.len());
+                                (&braced[..end], end + 2)
+                            }
+                            None => (rest.get(..4).unwrap_or(rest), 4),
+                        };
+                        match u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+                            Some(c) => s.push(c),
+                            None => return Err(self.error("invalid unicode escape")),
+                        }
+                        for _ in 0..len {
+                            chars.next();
+                        }
+                    }
+                    Some((_, c)) => s.push(c),
+                    None => break,
+                },
+                c => s.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    /// Reads up to the closing parenthesis of a call whose opening parenthesis has been read, and
+    /// consumes the closing parenthesis.
+    fn until_close(&mut self) -> Result<&str, ParseError> {
+        let start = self.pos;
+        let mut depth = 0;
+        while let Some(c) = self.rest().chars().next() {
+            match c {
+                '\'' | '"' | '`' => {
+                    self.string()?;
+                    continue;
+                }
+                '(' => depth += 1,
+                ')' if depth == 0 => {
+                    let end = self.pos;
+                    self.pos += 1;
+                    return Ok(self.js[start..end].trim());
+                }
+                ')' => depth -= 1,
+                '\n' => break,
+                _ => {}
+            }
+            self.pos += c.len_utf8();
+        }
+        Err(self.error("expected `)`"))
+    }
+
+    fn test_case(&mut self) -> Result<TestCase, ParseError> {
+        self.expect("tap.equal(")?;
+        self.skip_trivia();
+        self.expect("is(")?;
+        self.skip_trivia();
+        let argument = if self.rest().starts_with(['\'', '"', '`'].as_ref()) {
+            let s = self.string()?;
+            self.skip_trivia();
+            self.expect(")")?;
+            Argument::String(s)
+        } else if self.eat("() =>") {
+            Argument::Function(self.until_close()?.to_string())
+        } else {
+            Argument::Number(self.until_close()?.to_string())
+        };
+
+        let mut chain = Vec::new();
+        while self.eat(".") {
+            let name_len = self
+                .rest()
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(self.rest().len());
+            let name = self.rest()[..name_len].to_string();
+            self.pos += name_len;
+            let arguments = if self.eat("(") {
+                Some(self.until_close()?.to_string())
+            } else {
+                None
+            };
+            chain.push((name, arguments));
+        }
+        let (input, wasm_input) = self.translate(argument, &chain)?;
+
+        self.skip_trivia();
+        self.expect(",")?;
+        self.skip_trivia();
+        let expected = if self.eat("true") {
+            true
+        } else if self.eat("false") {
+            false
+        } else {
+            return Err(self.error("expected `true` or `false`"));
+        };
+        self.skip_trivia();
+        self.expect(")")?;
+        Ok(TestCase {
+            input,
+            wasm_input,
+            expected,
+        })
+    }
+
+    /// Returns the Rust expression for `is(argument)` followed by `chain`, and the one for the
+    /// JavaScript API.
+    fn translate(
+        &self,
+        argument: Argument,
+        chain: &[(String, Option<String>)],
+    ) -> Result<(String, Option<String>), ParseError> {
+        let chain: Vec<(&str, Option<&str>)> = chain
+            .iter()
+            .map(|(name, arguments)| (name.as_str(), arguments.as_deref()))
+            .collect();
+        let js_string = |s: &str| format!("is(JsValue::from_str({}))", rust_string(s));
+        let js_number = |n: &str| format!("is(JsValue::from_f64({}))", floats(n));
+        Ok(match (argument, chain.as_slice()) {
+            (Argument::String(s), [("thirteen", Some(""))]) => {
+                (rust_string(&s), Some(js_string(&s)))
+            }
+            (Argument::Number(n), [("thirteen", Some(""))]) => {
+                let wasm_input = js_number(&n);
+                (n, Some(wasm_input))
+            }
+            (Argument::Number(n), [("roughly", None), ("thirteen", Some(""))]) => (
+                format!("Roughly({})", floats(&n)),
+                Some(format!("{}.roughly()", js_number(&n))),
+            ),
+            (Argument::Function(body), [("returning", None), ("thirteen", Some(""))]) => (
+                format!("Returns(|| {})", body),
+                Some(format!(
+                    "is(js_sys::Function::new_no_args({}).into()).returning().unwrap()",
+                    rust_string(&format!("return {}", body))
+                )),
+            ),
+            (Argument::String(s), [("base", Some(radix)), ("thirteen", Some(""))]) => (
+                format!(
+                    "u8::from_str_radix({}, {}).unwrap()",
+                    rust_string(&s),
+                    radix
+                ),
+                Some(format!("{}.base({})", js_string(&s), radix)),
+            ),
+            (Argument::Number(n), [("yearOfBirth", Some(""))]) => {
+                (format!("{} - {}", REFERENCE_YEAR, n), None)
+            }
+            _ => return Err(self.error("no Rust translation for this assertion")),
+        })
+    }
+}
+
+/// Quotes `s`, leaving non-ASCII characters as they are.
+fn rust_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Turns the integer literals in a JavaScript expression into float literals.
+fn floats(expression: &str) -> String {
+    let mut converted = String::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(c) = chars.next() {
+        converted.push(c);
+        if c.is_ascii_digit() {
+            let mut has_point = false;
+            while let Some(&next) = chars.peek() {
+                if next.is_ascii_digit() || next == '.' {
+                    has_point |= next == '.';
+                    converted.push(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            if !has_point {
+                converted.push_str(".0");
+            }
+        }
+    }
+    converted
+}
+
+#[cfg(test)]
+#[path = "upstream_test.rs"]
+mod upstream_test;
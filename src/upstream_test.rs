@@ -0,0 +1,256 @@
+use super::*;
+#[cfg(all(feature = "numerals", feature = "languages"))]
+use crate::thirteen_strings::THIRTEEN_STRINGS;
+use rstest::rstest;
+use std::fs::read_to_string;
+
+fn vendored(path: &str) -> String {
+    read_to_string(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), path)).unwrap()
+}
+
+#[test]
+fn consts() {
+    let js = r#"
+        // Strings that are thirteen
+        const thirteenStrings = [
+            "xiii", // Roman numeral 13
+            // "skipped",
+            'baker\'s dozen', /* block */ `tab\there`,
+            "1\u{33}",
+            "xiii",
+        ];
+    "#;
+    assert_eq!(
+        parse_consts(js).unwrap(),
+        ["xiii", "baker's dozen", "tab\there", "13", "xiii"]
+    );
+    assert_eq!(
+        parse_consts("module.exports = { thirteenStrings: ['a', 'b'] };").unwrap(),
+        ["a", "b"]
+    );
+}
+
+#[rstest]
+#[case("const x = [];", 1)]
+#[case("const thirteenStrings = [\n\"a\",\nb];", 3)]
+#[case("const thirteenStrings = [\"a\" \"b\"];", 1)]
+#[case("const thirteenStrings = [\n\"a", 2)]
+fn consts_errors(#[case] js: &str, #[case] line: usize) {
+    assert_eq!(parse_consts(js).unwrap_err().line, line);
+}
+
+#[cfg(all(feature = "numerals", feature = "languages"))]
+#[test]
+fn comparison() {
+    let upstream: Vec<String> = ["XIII", "thirteen", "B", "Trece", "trece!", "TRECE!", "xiii"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let comparison = compare(&upstream, &THIRTEEN_STRINGS);
+    assert_eq!(comparison.new, ["trece!"]);
+    assert_eq!(
+        comparison.recased,
+        [("XIII".to_string(), "xiii"), ("Trece".to_string(), "trece")]
+    );
+    assert_eq!(comparison.missing.len(), THIRTEEN_STRINGS.len() - 3);
+    assert!(!comparison.missing.contains(&"thirteen"));
+}
+
+#[test]
+fn patch() {
+    let data = "# Comment\n\nentries = [\n    { value = \"a\", category = \"urls\" },\n]\n";
+    let comparison = Comparison {
+        new: vec!["https://13.example".to_string(), "tre\"ce".to_string()],
+        ..Comparison::default()
+    };
+    assert_eq!(
+        comparison.patch("data.toml", data),
+        concat!(
+            "--- a/data.toml\n",
+            "+++ b/data.toml\n",
+            "@@ -2,4 +2,6 @@\n",
+            " \n",
+            " entries = [\n",
+            "     { value = \"a\", category = \"urls\" },\n",
+            "+    { value = \"https://13.example\", category = \"urls\", note = \"from upstream\" },\n",
+            "+    { value = \"tre\\\"ce\", category = \"languages\", note = \"from upstream\" },\n",
+            " ]\n",
+        )
+    );
+    assert_eq!(Comparison::default().patch("data.toml", data), "");
+}
+
+#[rstest]
+#[case("tap.equal(is(13).thirteen(), true);", "13", true)]
+#[case(
+    "tap.equal(is('ba\\'k\"er').thirteen(), false);",
+    "\"ba'k\\\"er\"",
+    false
+)]
+#[case(
+    "tap.equal(is(((5.3 + 0.5) * 5 - 4) / 2).roughly.thirteen(), true);",
+    "Roughly(((5.3 + 0.5) * 5.0 - 4.0) / 2.0)",
+    true
+)]
+#[case(
+    "tap.equal(is(() => 13).returning.thirteen(), true);",
+    "Returns(|| 13)",
+    true
+)]
+#[case(
+    "tap.equal(is('d').base(16).thirteen(), true); // hex",
+    "u8::from_str_radix(\"d\", 16).unwrap()",
+    true
+)]
+#[case("  tap.equal(is(2003).yearOfBirth(), false);", "2021 - 2003", false)]
+fn tests(#[case] js: &str, #[case] input: &str, #[case] expected: bool) {
+    let js = format!("var tap = require('tap');\n\n{}\n", js);
+    let cases = parse_tests(&js).unwrap();
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].input, input);
+    assert_eq!(cases[0].expected, expected);
+}
+
+#[rstest]
+#[case(
+    "tap.equal(is(13).thirteen(), true);",
+    Some("is(JsValue::from_f64(13.0))")
+)]
+#[case(
+    "tap.equal(is('xiii').thirteen(), true);",
+    Some("is(JsValue::from_str(\"xiii\"))")
+)]
+#[case(
+    "tap.equal(is(((5.3 + 0.5) * 5 - 4) / 2).roughly.thirteen(), true);",
+    Some("is(JsValue::from_f64(((5.3 + 0.5) * 5.0 - 4.0) / 2.0)).roughly()")
+)]
+#[case(
+    "tap.equal(is(() => 13).returning.thirteen(), true);",
+    Some("is(js_sys::Function::new_no_args(\"return 13\").into()).returning().unwrap()")
+)]
+#[case(
+    "tap.equal(is('d').base(16).thirteen(), true);",
+    Some("is(JsValue::from_str(\"d\")).base(16)")
+)]
+#[case("tap.equal(is(2003).yearOfBirth(), false);", None)]
+fn wasm_tests(#[case] js: &str, #[case] wasm_input: Option<&str>) {
+    let cases = parse_tests(js).unwrap();
+    assert_eq!(cases[0].wasm_input.as_deref(), wasm_input);
+}
+
+#[test]
+fn tests_errors() {
+    let js = "tap.equal(is(13).thirteen(), true);\ntap.equal(is(13).not.thirteen(), false);\n";
+    let error = parse_tests(js).unwrap_err();
+    assert_eq!(error.line, 2);
+    assert_eq!(
+        error.to_string(),
+        "line 2: no Rust translation for this assertion"
+    );
+}
+
+#[test]
+fn generated() {
+    let cases = [
+        TestCase {
+            input: "13".to_string(),
+            wasm_input: Some("is(JsValue::from_f64(13.0))".to_string()),
+            expected: true,
+        },
+        TestCase {
+            input: format!("\"{}\"", "x".repeat(90)),
+            wasm_input: Some(format!("is(JsValue::from_str(\"{}\"))", "x".repeat(90))),
+            expected: false,
+        },
+        TestCase {
+            input: "2021 - 2003".to_string(),
+            wasm_input: None,
+            expected: false,
+        },
+    ];
+    let generated = generate_cases(&cases);
+    assert_eq!(
+        generated,
+        format!(
+            "#[case(13, true)] // 1\n#[case(\n    \"{}\",\n    false,\n)] // 2\n\
+             #[case(2021 - 2003, false)] // 3\n",
+            "x".repeat(90)
+        )
+    );
+
+    let lib_test = format!("a\n{}\nold\n{}\nb\n", BEGIN_GENERATED, END_GENERATED);
+    assert_eq!(
+        replace_generated(&lib_test, &generated).unwrap(),
+        format!(
+            "a\n{}\n{}{}\nb\n",
+            BEGIN_GENERATED, generated, END_GENERATED
+        )
+    );
+    assert!(replace_generated("a\n", &generated).is_err());
+
+    assert_eq!(
+        generate_string_cases(&cases),
+        format!(
+            "{}\n&[\n    (2, \"{}\", false),\n]\n",
+            BEGIN_GENERATED,
+            "x".repeat(90)
+        )
+    );
+
+    assert_eq!(
+        generate_chain_cases(&cases),
+        format!(
+            "{}\n&[\n    (1, || is(JsValue::from_f64(13.0)), true),\n]\n",
+            BEGIN_GENERATED
+        )
+    );
+}
+
+// Upstream's list has entries in every category
+#[cfg(all(
+    feature = "numerals",
+    feature = "languages",
+    feature = "leetspeak",
+    feature = "encodings",
+    feature = "hashes",
+    feature = "pop-culture",
+    feature = "politics",
+    feature = "urls"
+))]
+#[test]
+fn vendored_consts_are_in_sync() {
+    let entries = parse_consts(&vendored("upstream/consts.js")).unwrap();
+    let comparison = compare(&entries, &THIRTEEN_STRINGS);
+    assert_eq!(comparison.new, Vec::<String>::new());
+}
+
+#[test]
+fn vendored_tests_are_generated() {
+    let cases = parse_tests(&vendored("upstream/test.js")).unwrap();
+    let lib_test = vendored("src/lib_test.rs");
+    assert_eq!(
+        replace_generated(&lib_test, &generate_cases(&cases)).unwrap(),
+        lib_test,
+        "run `cargo run --features upstream -- upstream tests upstream/test.js src/lib_test.rs`"
+    );
+}
+
+#[test]
+fn vendored_string_cases_are_generated() {
+    let cases = parse_tests(&vendored("upstream/test.js")).unwrap();
+    assert_eq!(
+        generate_string_cases(&cases),
+        vendored("upstream/string_cases.rs"),
+        "run `cargo run --features upstream -- upstream tests upstream/test.js src/lib_test.rs`"
+    );
+}
+
+#[test]
+fn vendored_chain_cases_are_generated() {
+    let cases = parse_tests(&vendored("upstream/test.js")).unwrap();
+    assert_eq!(
+        generate_chain_cases(&cases),
+        vendored("upstream/chain_cases.rs"),
+        "run `cargo run --features upstream -- upstream tests upstream/test.js src/lib_test.rs`"
+    );
+}
@@ -0,0 +1,288 @@
+//! JavaScript API with the same chain syntax as the original
+//! [is-thirteen](https://github.com/jezen/is-thirteen).
+//!
+//! ```js
+//! import { is } from "is-thirteen";
+//!
+//! is(13).thirteen();                  // true
+//! is(12.8).roughly.thirteen();        // true
+//! is(12).within(1).of.thirteen();     // true
+//! is("XIII").not.thirteen();          // false
+//! is(() => 13).returning.thirteen();  // true
+//! ```
+//!
+//! Every verdict is computed by the Rust [`IsThirteen`] implementations.
+
+use crate::{
+    AnagramOf, AtomicNumber, Backwards, CanSpell, DivisibleBy, GreaterThan, IsThirteen, LessThan,
+    Roughly, Within,
+};
+use wasm_bindgen::prelude::*;
+
+/// A JavaScript value passed to [`is`].
+#[derive(Debug, Clone)]
+enum Value {
+    Number(f64),
+    String(String),
+    Function(js_sys::Function),
+    /// Booleans, `null`, `undefined`, objects and anything else that is never thirteen.
+    Other,
+}
+
+impl Value {
+    fn from_js(value: JsValue) -> Self {
+        if let Some(n) = value.as_f64() {
+            Value::Number(n)
+        } else if let Some(s) = value.as_string() {
+            Value::String(s)
+        } else if value.is_function() {
+            Value::Function(value.into())
+        } else {
+            Value::Other
+        }
+    }
+
+    /// Converts strings to numbers like JavaScript arithmetic does.
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            Value::String(s) => s.trim().parse().ok(),
+            Value::Function(_) | Value::Other => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl IsThirteen for Value {
+    fn thirteen(&self) -> bool {
+        match self {
+            Value::Number(n) => n.thirteen(),
+            Value::String(s) => s.as_str().thirteen(),
+            Value::Function(_) | Value::Other => false,
+        }
+    }
+}
+
+/// The comparison that [`Is::thirteen`] makes.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Comparison {
+    Equals,
+    Roughly,
+    DivisibleBy,
+    SquareOf,
+    GreaterThan,
+    LessThan,
+    Within(f64),
+    CanSpell,
+    AnagramOf,
+    Backwards,
+    AtomicNumber,
+}
+
+/// Returned by [`is`]. Property accesses such as `.roughly` and `.not` return a new `Is`, and
+/// [`Is::thirteen`] ends the chain.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Is {
+    value: Value,
+    comparison: Comparison,
+    negated: bool,
+}
+
+/// Starts a chain. `is(x).thirteen()` is `true` if `x` is thirteen.
+#[wasm_bindgen]
+pub fn is(value: JsValue) -> Is {
+    Is::new(Value::from_js(value))
+}
+
+impl Is {
+    fn new(value: Value) -> Self {
+        Self {
+            value,
+            comparison: Comparison::Equals,
+            negated: false,
+        }
+    }
+
+    fn compare(&self, comparison: Comparison) -> Self {
+        Self {
+            comparison,
+            ..self.clone()
+        }
+    }
+
+    fn map_number(&self, f: impl FnOnce(f64) -> f64) -> Self {
+        let value = match self.value.as_number() {
+            Some(n) => Value::Number(f(n)),
+            None => Value::Other,
+        };
+        Self {
+            value,
+            ..self.clone()
+        }
+    }
+
+    fn verdict(&self) -> bool {
+        let number = self.value.as_number();
+        let string = self.value.as_str();
+        match self.comparison {
+            Comparison::Equals => self.value.thirteen(),
+            Comparison::Roughly => number.is_some_and(|n| Roughly(n).thirteen()),
+            Comparison::DivisibleBy => number.is_some_and(|n| DivisibleBy(n).thirteen()),
+            Comparison::SquareOf => number.is_some_and(|n| n.sqrt().thirteen()),
+            Comparison::GreaterThan => number.is_some_and(|n| GreaterThan(n).thirteen()),
+            Comparison::LessThan => number.is_some_and(|n| LessThan(n).thirteen()),
+            Comparison::Within(radius) => number.is_some_and(|n| Within::new(n, radius).thirteen()),
+            Comparison::CanSpell => string.is_some_and(|s| CanSpell::new(s).thirteen()),
+            Comparison::AnagramOf => string.is_some_and(|s| AnagramOf::new(s).thirteen()),
+            Comparison::Backwards => string.is_some_and(|s| Backwards(s).thirteen()),
+            Comparison::AtomicNumber => string.is_some_and(|s| AtomicNumber(s).thirteen()),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Is {
+    /// Ends the chain.
+    pub fn thirteen(&self) -> bool {
+        self.verdict() != self.negated
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn roughly(&self) -> Is {
+        self.compare(Comparison::Roughly)
+    }
+
+    /// Calls the function passed to [`is`] and checks its return value.
+    #[wasm_bindgen(getter)]
+    pub fn returning(&self) -> Result<Is, JsValue> {
+        match &self.value {
+            Value::Function(f) => Ok(Is {
+                value: Value::from_js(f.call0(&JsValue::NULL)?),
+                ..self.clone()
+            }),
+            _ => Err(js_sys::TypeError::new("is(x).returning requires a function").into()),
+        }
+    }
+
+    #[wasm_bindgen(getter = not)]
+    pub fn negated(&self) -> Is {
+        Is {
+            negated: !self.negated,
+            ..self.clone()
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn divisible(&self) -> Is {
+        self.compare(Comparison::DivisibleBy)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn square(&self) -> Is {
+        self.compare(Comparison::SquareOf)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn greater(&self) -> Is {
+        self.compare(Comparison::GreaterThan)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn less(&self) -> Is {
+        self.compare(Comparison::LessThan)
+    }
+
+    pub fn within(&self, radius: f64) -> Is {
+        self.compare(Comparison::Within(radius))
+    }
+
+    /// Filler for `.divisible.by`.
+    #[wasm_bindgen(getter)]
+    pub fn by(&self) -> Is {
+        self.clone()
+    }
+
+    /// Filler for `.square.of` and `.within(n).of`.
+    #[wasm_bindgen(getter)]
+    pub fn of(&self) -> Is {
+        self.clone()
+    }
+
+    /// Filler for `.greater.than` and `.less.than`.
+    #[wasm_bindgen(getter)]
+    pub fn than(&self) -> Is {
+        self.clone()
+    }
+
+    #[wasm_bindgen(getter = canSpell)]
+    pub fn can_spell(&self) -> Is {
+        self.compare(Comparison::CanSpell)
+    }
+
+    #[wasm_bindgen(getter = anagramOf)]
+    pub fn anagram_of(&self) -> Is {
+        self.compare(Comparison::AnagramOf)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn backwards(&self) -> Is {
+        self.compare(Comparison::Backwards)
+    }
+
+    #[wasm_bindgen(getter = atomicNumber)]
+    pub fn atomic_number(&self) -> Is {
+        self.compare(Comparison::AtomicNumber)
+    }
+
+    pub fn plus(&self, n: f64) -> Is {
+        self.map_number(|x| x + n)
+    }
+
+    pub fn minus(&self, n: f64) -> Is {
+        self.map_number(|x| x - n)
+    }
+
+    pub fn times(&self, n: f64) -> Is {
+        self.map_number(|x| x * n)
+    }
+
+    pub fn dividedby(&self, n: f64) -> Is {
+        self.map_number(|x| x / n)
+    }
+
+    /// Parses the value as an integer in `radix`, like `parseInt(x, radix)`.
+    pub fn base(&self, radix: u32) -> Is {
+        let digits = match &self.value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Function(_) | Value::Other => String::new(),
+        };
+        let value = match (2..=36).contains(&radix) {
+            true => i64::from_str_radix(digits.trim(), radix)
+                .map_or(Value::Other, |n| Value::Number(n as f64)),
+            false => Value::Other,
+        };
+        Is {
+            value,
+            ..self.clone()
+        }
+    }
+
+    /// Returns `true` if someone born in the given year is 13 this year.
+    #[wasm_bindgen(js_name = yearOfBirth)]
+    pub fn year_of_birth(&self) -> bool {
+        let this_year = js_sys::Date::new_0().get_full_year() as f64;
+        self.map_number(|year| this_year - year).thirteen()
+    }
+}
+
+#[cfg(test)]
+#[path = "wasm_test.rs"]
+mod wasm_test;
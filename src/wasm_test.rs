@@ -0,0 +1,57 @@
+use super::*;
+use rstest::rstest;
+
+fn number(n: f64) -> Is {
+    Is::new(Value::Number(n))
+}
+
+fn string(s: &str) -> Is {
+    Is::new(Value::String(s.to_string()))
+}
+
+#[rstest]
+#[case(number(13.0), true)]
+#[case(number(14.0), false)]
+#[case(string("13"), true)]
+#[case(string("XIII"), true)]
+#[case(string("b"), false)]
+#[case(Is::new(Value::Other), false)]
+#[case(number(13.0).negated(), false)]
+#[case(number(14.0).negated(), true)]
+#[case(number(13.0).negated().negated(), true)]
+#[case(Is::new(Value::Other).negated(), true)]
+#[case(number(((5.3 + 0.5) * 5.0 - 4.0) / 2.0).roughly(), true)]
+#[case(number(13.5).roughly(), false)]
+#[case(number(13.0).roughly().negated(), false)]
+#[case(number(26.0).divisible().by(), true)]
+#[case(number(27.0).divisible().by(), false)]
+#[case(number(169.0).square().of(), true)]
+#[case(number(13.0).square().of(), false)]
+#[case(number(14.0).greater().than(), true)]
+#[case(number(13.0).greater().than(), false)]
+#[case(number(12.0).less().than(), true)]
+#[case(string("12").less().than(), true)]
+#[case(number(12.0).within(1.0).of(), true)]
+#[case(number(11.0).within(1.0).of(), false)]
+#[case(number(11.0).plus(2.0), true)]
+#[case(number(15.0).minus(2.0), true)]
+#[case(number(6.5).times(2.0), true)]
+#[case(number(26.0).dividedby(2.0), true)]
+#[case(string("XIII").plus(0.0), false)]
+#[case(string("eihbtrtAecdn").can_spell(), true)]
+#[case(string("nRteehit").anagram_of(), true)]
+#[case(string("neetRiht").backwards(), true)]
+#[case(string("Aluminum").atomic_number(), true)]
+#[case(number(13.0).atomic_number(), false)]
+#[case(string("1101").base(2), true)]
+#[case(string("1111").base(2), false)]
+#[case(string("15").base(8), true)]
+#[case(string("13").base(8), false)]
+#[case(string("d").base(16), true)]
+#[case(string("D").base(16), true)]
+#[case(string("A").base(16), false)]
+#[case(number(15.0).base(8), true)]
+#[case(string("d").base(37), false)]
+fn test_chain(#[case] chain: Is, #[case] expected: bool) {
+    assert_eq!(chain.thirteen(), expected);
+}
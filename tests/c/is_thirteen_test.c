@@ -0,0 +1,51 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "is_thirteen.h"
+
+static int failures = 0;
+
+#define CHECK(expr, expected)                                      \
+    do {                                                           \
+        if ((expr) != (expected)) {                                \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #expr); \
+            failures++;                                            \
+        }                                                          \
+    } while (0)
+
+#define STR(s) (s), strlen(s)
+
+int main(void) {
+    CHECK(is_thirteen_str(STR("13")), true);
+    CHECK(is_thirteen_str(STR("Thirteen")), true);
+    CHECK(is_thirteen_str(STR("\xe5\x8d\x81\xe4\xb8\x89")), true); /* 十三 */
+    CHECK(is_thirteen_str(STR("14")), false);
+    CHECK(is_thirteen_str("13", 1), false);
+    CHECK(is_thirteen_str(NULL, 0), false);
+
+    CHECK(is_thirteen_i64(13), true);
+    CHECK(is_thirteen_i64(14), false);
+    CHECK(is_thirteen_f64(13.0), true);
+    CHECK(is_thirteen_char('B'), true);
+
+    CHECK(is_thirteen_roughly(12.5), true);
+    CHECK(is_thirteen_roughly(13.5), false);
+    CHECK(is_thirteen_within(12.0, 1.0), true);
+    CHECK(is_thirteen_divisible_by_i64(26), true);
+    CHECK(is_thirteen_divisible_by_f64(1.0), false);
+    CHECK(is_thirteen_greater_than_i64(14), true);
+    CHECK(is_thirteen_greater_than_f64(13.0), false);
+    CHECK(is_thirteen_less_than_i64(12), true);
+    CHECK(is_thirteen_less_than_f64(13.0), false);
+
+    CHECK(is_thirteen_can_spell(STR("eihbtrtAecdn")), true);
+    CHECK(is_thirteen_anagram_of(STR("nRteehit")), true);
+    CHECK(is_thirteen_backwards(STR("neetRiht")), true);
+    CHECK(is_thirteen_atomic_number(STR("Aluminum")), true);
+
+    if (failures) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
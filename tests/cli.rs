@@ -0,0 +1,64 @@
+//! Runs the `is-thirteen` binary.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_is-thirteen"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Write from another thread so that a full stdout pipe cannot block the child. Errors are
+    // ignored because the child may exit without reading its input.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().unwrap();
+    let _ = writer.join().unwrap();
+    output
+}
+
+#[cfg(feature = "numerals")]
+#[test]
+fn stdin() {
+    let output = run(&[], "XIII\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"true\n");
+}
+
+#[cfg(feature = "languages")]
+#[test]
+fn lines() {
+    let output = run(&["--lines"], "13\n14\r\nthirteen\n\nfourteen");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"true\nfalse\ntrue\nfalse\nfalse\n");
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn lines_jobs() {
+    let input: String = (0..200_000).map(|n| format!("{}\n", n % 20)).collect();
+    let expected: String = (0..200_000)
+        .map(|n| format!("{}\n", n % 20 == 13))
+        .collect();
+    let output = run(&["--lines", "--jobs", "4"], &input);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
+
+#[cfg(not(feature = "rayon"))]
+#[test]
+fn lines_jobs() {
+    let output = run(&["--lines", "--jobs", "4"], "13\n");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("rayon"));
+}
+
+#[test]
+fn invalid_jobs() {
+    let output = run(&["--lines", "--jobs", "many"], "13\n");
+    assert!(!output.status.success());
+}
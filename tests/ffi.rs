@@ -0,0 +1,36 @@
+//! Builds `tests/c/is_thirteen_test.c` against the static library and runs it.
+#![cfg(all(feature = "ffi", unix))]
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn c_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    // `cargo test` only builds the rlib, so build the static library separately
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--lib", "--crate-type", "staticlib"])
+        .args(["--features", "ffi", "--manifest-path"])
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to build the static library");
+
+    let program = target_dir.join("is_thirteen_test");
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg(manifest_dir.join("tests/c/is_thirteen_test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(target_dir.join("debug/libis_thirteen.a"))
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to compile the C test program");
+
+    assert!(Command::new(&program).status().unwrap().success());
+}
@@ -0,0 +1,38 @@
+//! Builds the Python extension module and runs `tests/python/smoke_test.py` against it.
+#![cfg(all(feature = "python", target_os = "linux"))]
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn smoke_test() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("python");
+
+    // `cargo test` only builds the rlib, so build the extension module separately
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--lib", "--crate-type", "cdylib"])
+        .args(["--features", "python", "--manifest-path"])
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to build the extension module");
+
+    let module_dir = target_dir.join("module");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::copy(
+        target_dir.join("debug/libis_thirteen.so"),
+        module_dir.join("is_thirteen.so"),
+    )
+    .unwrap();
+
+    let python = std::env::var("PYO3_PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let status = Command::new(python)
+        .arg(manifest_dir.join("tests/python/smoke_test.py"))
+        .env("PYTHONPATH", &module_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -0,0 +1,39 @@
+"""Smoke test for the is_thirteen extension module. The module must be on PYTHONPATH."""
+
+from is_thirteen import (
+    AnagramOf,
+    AtomicNumber,
+    Backwards,
+    CanSpell,
+    DivisibleBy,
+    Roughly,
+    Within,
+    is_thirteen,
+    is_thirteen_many,
+)
+
+assert is_thirteen(13)
+assert is_thirteen(13.0)
+assert is_thirteen("XIII")
+assert is_thirteen(b"thirteen")
+assert is_thirteen(13 + 0j)
+assert not is_thirteen(14)
+assert not is_thirteen(True)
+assert is_thirteen_many([13, "14", "B"]) == [True, False, True]
+
+assert Roughly(13.4).thirteen()
+assert Within(12.0, 1.0).thirteen()
+assert DivisibleBy(39).thirteen()
+assert CanSpell("eihbtrtAecdn").thirteen()
+assert AnagramOf("nRteehit").thirteen()
+assert Backwards("neetRiht").thirteen()
+assert AtomicNumber("Aluminum").thirteen()
+
+try:
+    is_thirteen(object())
+except TypeError:
+    pass
+else:
+    raise AssertionError("expected TypeError")
+
+print("ok")
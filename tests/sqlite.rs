@@ -0,0 +1,52 @@
+//! Builds the loadable extension and runs queries against it with the `sqlite3` shell.
+#![cfg(all(feature = "sqlite", target_os = "linux"))]
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+#[test]
+fn load_extension() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("sqlite");
+
+    // `cargo test` only builds the rlib, so build the extension separately
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--lib", "--crate-type", "cdylib"])
+        .args(["--features", "sqlite-extension"])
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success(), "failed to build the extension");
+
+    let sqlite3 = std::env::var("SQLITE3").unwrap_or_else(|_| "sqlite3".to_string());
+    let mut child = Command::new(sqlite3)
+        .arg("-cmd")
+        .arg(format!(
+            ".load {}",
+            target_dir.join("debug/libis_thirteen").display()
+        ))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(
+            b"SELECT is_thirteen(13), is_thirteen('xiii'), is_thirteen(14), \
+              thirteen_reason('Thirteen'), \
+              (SELECT count(*) FROM thirteen_entries WHERE entry = 'thirteen');",
+        )
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "1|1|0|entry \"thirteen\"|1\n"
+    );
+}
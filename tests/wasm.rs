@@ -0,0 +1,71 @@
+//! Runs the upstream is-thirteen test suite against the JavaScript API under Node.js:
+//!
+//! ```sh
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+//! ```
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use is_thirteen::wasm::{is, Is};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Upstream cases that call `is(x).thirteen()` on a string, generated from upstream/test.js. The
+/// numbers match those in `src/lib_test.rs`.
+const STRING_CASES: &[(u32, &str, bool)] = include!("../upstream/string_cases.rs");
+
+/// Upstream cases that call `is(x).thirteen()` on a number or chain other calls before it,
+/// generated from upstream/test.js. `yearOfBirth()` depends on the clock and is tested below.
+const CHAIN_CASES: &[(u32, Chain, bool)] = include!("../upstream/chain_cases.rs");
+
+/// Builds an `is(x)` chain up to `.thirteen()`.
+type Chain = fn() -> Is;
+
+#[wasm_bindgen_test]
+fn strings() {
+    for &(n, s, expected) in STRING_CASES {
+        assert_eq!(is(JsValue::from_str(s)).thirteen(), expected, "case {}", n);
+    }
+}
+
+#[wasm_bindgen_test]
+fn chains() {
+    for &(n, chain, expected) in CHAIN_CASES {
+        assert_eq!(chain().thirteen(), expected, "case {}", n);
+    }
+}
+
+#[wasm_bindgen_test]
+fn year_of_birth() {
+    // Upstream case 27
+    let this_year = js_sys::Date::new_0().get_full_year() as f64;
+    assert!(is(JsValue::from_f64(this_year - 13.0)).year_of_birth());
+    assert!(!is(JsValue::from_f64(this_year - 18.0)).year_of_birth());
+}
+
+#[wasm_bindgen_test]
+fn arithmetic() {
+    let n = |x: f64| is(JsValue::from_f64(x));
+    assert!(n(25.0).minus(12.0).thirteen());
+    assert!(n(1.0).plus(12.0).thirteen());
+    assert!(n(2.0 * 8.0 + 11.0 - 1.0).dividedby(2.0).thirteen());
+    assert!(!n(10.0 - 1.0 + 32.0).dividedby(4.0).times(3.0).thirteen());
+}
+
+#[wasm_bindgen_test]
+fn returning_requires_a_function() {
+    assert!(is(JsValue::from_f64(13.0)).returning().is_err());
+}
+
+#[wasm_bindgen_test]
+fn not() {
+    assert!(!is(JsValue::from_f64(13.0)).negated().thirteen());
+    assert!(is(JsValue::from_str("b")).negated().thirteen());
+}
+
+#[wasm_bindgen_test]
+fn other_values() {
+    assert!(!is(JsValue::TRUE).thirteen());
+    assert!(!is(JsValue::NULL).thirteen());
+    assert!(!is(JsValue::UNDEFINED).thirteen());
+}
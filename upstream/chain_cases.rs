@@ -0,0 +1,19 @@
+// Generated from upstream/test.js by `is-thirteen upstream tests`. Do not edit.
+&[
+    (1, || is(JsValue::from_f64(13.0)), true),
+    (184, || is(JsValue::from_f64(25.0 - 12.0)), true),
+    (185, || is(JsValue::from_f64(1.0 + 12.0)), true),
+    (186, || is(JsValue::from_f64((2.0 * 8.0 + 11.0 - 1.0) / 2.0)), true),
+    (187, || is(JsValue::from_f64((10.0 - 1.0 + 32.0) / 4.0 * 3.0)), false),
+    (188, || is(JsValue::from_f64(((5.3 + 0.5) * 5.0 - 4.0) / 2.0)).roughly(), true),
+    (189, || is(JsValue::from_f64(13.0)), true),
+    (190, || is(JsValue::from_f64(14.0)), false),
+    (191, || is(JsValue::from_str("1101")).base(2), true),
+    (192, || is(JsValue::from_str("1111")).base(2), false),
+    (193, || is(JsValue::from_str("15")).base(8), true),
+    (194, || is(JsValue::from_str("13")).base(8), false),
+    (195, || is(JsValue::from_str("d")).base(16), true),
+    (196, || is(JsValue::from_str("D")).base(16), true),
+    (197, || is(JsValue::from_str("A")).base(16), false),
+    (198, || is(js_sys::Function::new_no_args("return 13").into()).returning().unwrap(), true),
+]
@@ -0,0 +1,191 @@
+// Generated from upstream/test.js by `is-thirteen upstream tests`. Do not edit.
+&[
+    (2, "13", true),
+    (3, "۱۳", true),
+    (4, "XIII", true),
+    (5, "xiii", true),
+    (6, "IIIIIIIIIIIII", true),
+    (7, "IlIlIlIlIlIlI", true),
+    (8, "https://en.wikipedia.org/wiki/This_Is_Thirteen", true),
+    (9, "https://scontent.cdninstagram.com/hphotos-xtf1/t51.2885-15/s320x320/e35/12237511_444845689040315_1101385461_n.jpg", true),
+    (10, "http://www.metal-archives.com/images/1/5/3/7/153772.jpg", false),
+    (11, "https://www.youtube.com/watch?v=pte3Jg-2Ax4", true),
+    (12, "https://www.youtube.com/watch?v=33Kv5D2zwyc", true),
+    (13, "thirteen", true),
+    (14, "Thirteen", true),
+    (15, "Remy Hadley", true),
+    (16, "Olivia Wilde", true),
+    (17, "weedle", true),
+    (18, "baker's dozen", true),
+    (19, "Dr. Remy Beauregard Hadley", true),
+    (20, "Patty Tsai", true),
+    (21, "PT", true),
+    (22, "Washington Luís", true),
+    (23, "Millard Fillmore", true),
+    (24, "https://en.wikipedia.org/wiki/XIII_(video_game)", true),
+    (25, "http://www.imdb.com/title/tt0798817/", true),
+    (26, "https://www.imdb.com/title/tt2991516/", true),
+    (28, "13+0i", true),
+    (29, "13i", true),
+    (30, "13 + 13i", true),
+    (31, "12i", false),
+    (32, "Ei", true),
+    (33, "EI", true),
+    (34, "E1", true),
+    (35, "El", true),
+    (36, "E|", true),
+    (37, "ƖƐ", true),
+    (38, "ƐƖ", true),
+    (39, "th1rt33n", true),
+    (40, "th1rte3n", true),
+    (41, "th1rteen", true),
+    (42, "thirt3en", true),
+    (43, "thirt33n", true),
+    (44, "thirte3n", true),
+    (45, "dertien", true),
+    (46, "ثلاثة عشر", true),
+    (47, "تلطاشر", true),
+    (48, "تلتاشر", true),
+    (49, "طلتاشر", true),
+    (50, "طلطاشر", true),
+    (51, "dertiendertien", true),
+    (52, "seri-un-teng", true),
+    (53, "seriunteng", true),
+    (54, "serí-un-teng", true),
+    (55, "seríunteng", true),
+    (56, "тринадесет", true),
+    (57, "тринайсет", true),
+    (58, "tretze", true),
+    (59, "napulo ug tulo", true),
+    (60, "třináct", true),
+    (61, "十三", true),
+    (62, "拾參", true),
+    (63, "拾叁", true),
+    (64, "拾叄", true),
+    (65, "拾参", true),
+    (66, "trinaest", true),
+    (67, "tretten", true),
+    (68, "senthi", true),
+    (69, "kolmteist", true),
+    (70, "thirteen", true),
+    (71, "labintatlo", true),
+    (72, "kolmetoista", true),
+    (73, "treize", true),
+    (74, "treizième", true),
+    (75, "dreizehn", true),
+    (76, "ცამეტი", true),
+    (77, "‘umikūmākolu", true),
+    (78, "שלוש עשרה", true),
+    (79, "שלושעשרה", true),
+    (80, "ֹשְלֹש- עֶשְֹרֵה", true),
+    (81, "שלושה עשר", true),
+    (82, "שלושהעשר", true),
+    (83, "ֹשְלֹשָה- עָשָֹר", true),
+    (84, "יג", true),
+    (85, "י״ג", true),
+    (86, "तेरह", true),
+    (87, "tizenhárom", true),
+    (88, "trí déag", true),
+    (89, "tredici", true),
+    (90, "on üç", true),
+    (91, "ಹದಿಮೂರು", true),
+    (92, "పదమూడు", true),
+    (93, "೧೩", true),
+    (94, "열셋", true),
+    (95, "십삼", true),
+    (96, "sêzdeh", true),
+    (97, "tredecim", true),
+    (98, "trīspadsmit", true),
+    (99, "trylika", true),
+    (100, "dräizéng", true),
+    (101, "тринаесет", true),
+    (102, "tiga belas", true),
+    (103, "арван", true),
+    (104, ".---- ...--", true),
+    (105, "matlactlihuan yei", true),
+    (106, "mahtlactli omei", true),
+    (107, "mahtlactli ihuan yei", true),
+    (108, "irteenthay", true),
+    (109, "trzynaście", true),
+    (110, "trzynasty", true),
+    (111, "trzynasta", true),
+    (112, "trzynaste", true),
+    (113, "trzynaści", true),
+    (114, "trzynastego", true),
+    (115, "trzynastej", true),
+    (116, "trzynastych", true),
+    (117, "trzynastemu", true),
+    (118, "trzynastym", true),
+    (119, "trzynastą", true),
+    (120, "trzynastymi", true),
+    (121, "trzynastu", true),
+    (122, "trzynastek", true),
+    (123, "trzynastoma", true),
+    (124, "trzynaścioro", true),
+    (125, "trzynastka", true),
+    (126, "trzynastki", true),
+    (127, "trzynastką", true),
+    (128, "trzynastce", true),
+    (129, "trzynastko", true),
+    (130, "trzynaściorgiem", true),
+    (131, "trzynaściorgu", true),
+    (132, "trzynaściorga", true),
+    (133, "trzynastokrotny", true),
+    (134, "trzynastokrotnie", true),
+    (135, "trzynastokrotną", true),
+    (136, "trzynastokrotnemu", true),
+    (137, "trzynastokrotnej", true),
+    (138, "trzynastokrotnych", true),
+    (139, "trzynastokrotność", true),
+    (140, "trzynastokrotności", true),
+    (141, "trzynastokrotnością", true),
+    (142, "treze", true),
+    (143, "ਤੇਰਾਂ", true),
+    (144, "੧੩", true),
+    (145, "treisprezece", true),
+    (146, "тринадцать", true),
+    (147, "тринаест", true),
+    (148, "trinásť", true),
+    (149, "wa’maH wej", true),
+    (150, "trinajst", true),
+    (151, "trece", true),
+    (152, "dektri", true),
+    (153, "trese", true),
+    (154, "tretton", true),
+    (155, "பதின்மூன்று", true),
+    (156, "สิบสาม", true),
+    (157, "тринадцять", true),
+    (158, "تیرہ", true),
+    (159, "tayra", true),
+    (160, "tri ar ddeg", true),
+    (161, "דרייַצן", true),
+    (162, "דרייצן", true),
+    (163, "kumi na tatu", true),
+    (164, "പതിമൂന്ന്", true),
+    (165, "१३", true),
+    (166, "तेह्र", true),
+    (167, "quainel", true),
+    (168, "mînuiug", true),
+    (169, "7h1r733n", true),
+    (170, "B", true),
+    (171, "b", false),
+    (172, "ß", true),
+    (173, "ẞ", true),
+    (174, "Β", true),
+    (175, "β", true),
+    (176, "阝", true),
+    (177, "i3", true),
+    (178, "I3", true),
+    (179, "l3", true),
+    (180, "L3", true),
+    (181, "|3", true),
+    (182, "!3", true),
+    (183, "Dilma", true),
+    (199, "|||||||||||||", true),
+    (200, "/////////////", true),
+    (201, "🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱", true),
+    (202, "oooooooooooooo", false),
+    (203, "bbbbbbbbbbb", false),
+    (204, "||h||||||||||", false),
+    (205, "///i/////////", false),
+]
@@ -0,0 +1,208 @@
+var tap = require('tap');
+var is = require('./index');
+
+tap.equal(is(13).thirteen(), true);
+tap.equal(is('13').thirteen(), true);
+tap.equal(is('۱۳').thirteen(), true);
+tap.equal(is('XIII').thirteen(), true);
+tap.equal(is('xiii').thirteen(), true);
+tap.equal(is('IIIIIIIIIIIII').thirteen(), true);
+tap.equal(is('IlIlIlIlIlIlI').thirteen(), true);
+tap.equal(is('https://en.wikipedia.org/wiki/This_Is_Thirteen').thirteen(), true);
+tap.equal(is('https://scontent.cdninstagram.com/hphotos-xtf1/t51.2885-15/s320x320/e35/12237511_444845689040315_1101385461_n.jpg').thirteen(), true);
+tap.equal(is('http://www.metal-archives.com/images/1/5/3/7/153772.jpg').thirteen(), false);
+tap.equal(is('https://www.youtube.com/watch?v=pte3Jg-2Ax4').thirteen(), true);
+tap.equal(is('https://www.youtube.com/watch?v=33Kv5D2zwyc').thirteen(), true);
+tap.equal(is('thirteen').thirteen(), true);
+tap.equal(is('Thirteen').thirteen(), true);
+tap.equal(is('Remy Hadley').thirteen(), true);
+tap.equal(is('Olivia Wilde').thirteen(), true);
+tap.equal(is('weedle').thirteen(), true);
+tap.equal(is('baker\'s dozen').thirteen(), true);
+tap.equal(is('Dr. Remy Beauregard Hadley').thirteen(), true);
+tap.equal(is('Patty Tsai').thirteen(), true);
+tap.equal(is('PT').thirteen(), true);
+tap.equal(is('Washington Luís').thirteen(), true);
+tap.equal(is('Millard Fillmore').thirteen(), true);
+tap.equal(is('https://en.wikipedia.org/wiki/XIII_(video_game)').thirteen(), true);
+tap.equal(is('http://www.imdb.com/title/tt0798817/').thirteen(), true);
+tap.equal(is('https://www.imdb.com/title/tt2991516/').thirteen(), true);
+tap.equal(is(2003).yearOfBirth(), false);
+tap.equal(is('13+0i').thirteen(), true);
+tap.equal(is('13i').thirteen(), true);
+tap.equal(is('13 + 13i').thirteen(), true);
+tap.equal(is('12i').thirteen(), false);
+tap.equal(is('Ei').thirteen(), true);
+tap.equal(is('EI').thirteen(), true);
+tap.equal(is('E1').thirteen(), true);
+tap.equal(is('El').thirteen(), true);
+tap.equal(is('E|').thirteen(), true);
+tap.equal(is('ƖƐ').thirteen(), true);
+tap.equal(is('ƐƖ').thirteen(), true);
+tap.equal(is('th1rt33n').thirteen(), true);
+tap.equal(is('th1rte3n').thirteen(), true);
+tap.equal(is('th1rteen').thirteen(), true);
+tap.equal(is('thirt3en').thirteen(), true);
+tap.equal(is('thirt33n').thirteen(), true);
+tap.equal(is('thirte3n').thirteen(), true);
+tap.equal(is('dertien').thirteen(), true);
+tap.equal(is('ثلاثة عشر').thirteen(), true);
+tap.equal(is('تلطاشر').thirteen(), true);
+tap.equal(is('تلتاشر').thirteen(), true);
+tap.equal(is('طلتاشر').thirteen(), true);
+tap.equal(is('طلطاشر').thirteen(), true);
+tap.equal(is('dertiendertien').thirteen(), true);
+tap.equal(is('seri-un-teng').thirteen(), true);
+tap.equal(is('seriunteng').thirteen(), true);
+tap.equal(is('serí-un-teng').thirteen(), true);
+tap.equal(is('seríunteng').thirteen(), true);
+tap.equal(is('тринадесет').thirteen(), true);
+tap.equal(is('тринайсет').thirteen(), true);
+tap.equal(is('tretze').thirteen(), true);
+tap.equal(is('napulo ug tulo').thirteen(), true);
+tap.equal(is('třináct').thirteen(), true);
+tap.equal(is('十三').thirteen(), true);
+tap.equal(is('拾參').thirteen(), true);
+tap.equal(is('拾叁').thirteen(), true);
+tap.equal(is('拾叄').thirteen(), true);
+tap.equal(is('拾参').thirteen(), true);
+tap.equal(is('trinaest').thirteen(), true);
+tap.equal(is('tretten').thirteen(), true);
+tap.equal(is('senthi').thirteen(), true);
+tap.equal(is('kolmteist').thirteen(), true);
+tap.equal(is('thirteen').thirteen(), true);
+tap.equal(is('labintatlo').thirteen(), true);
+tap.equal(is('kolmetoista').thirteen(), true);
+tap.equal(is('treize').thirteen(), true);
+tap.equal(is('treizième').thirteen(), true);
+tap.equal(is('dreizehn').thirteen(), true);
+tap.equal(is('ცამეტი').thirteen(), true);
+tap.equal(is('‘umikūmākolu').thirteen(), true);
+tap.equal(is('שלוש עשרה').thirteen(), true);
+tap.equal(is('שלושעשרה').thirteen(), true);
+tap.equal(is('ֹשְלֹש- עֶשְֹרֵה').thirteen(), true);
+tap.equal(is('שלושה עשר').thirteen(), true);
+tap.equal(is('שלושהעשר').thirteen(), true);
+tap.equal(is('ֹשְלֹשָה- עָשָֹר').thirteen(), true);
+tap.equal(is('יג').thirteen(), true);
+tap.equal(is('י״ג').thirteen(), true);
+tap.equal(is('तेरह').thirteen(), true);
+tap.equal(is('tizenhárom').thirteen(), true);
+tap.equal(is('trí déag').thirteen(), true);
+tap.equal(is('tredici').thirteen(), true);
+tap.equal(is('on üç').thirteen(), true);
+tap.equal(is('ಹದಿಮೂರು').thirteen(), true);
+tap.equal(is('పదమూడు').thirteen(), true);
+tap.equal(is('೧೩').thirteen(), true);
+tap.equal(is('열셋').thirteen(), true);
+tap.equal(is('십삼').thirteen(), true);
+tap.equal(is('sêzdeh').thirteen(), true);
+tap.equal(is('tredecim').thirteen(), true);
+tap.equal(is('trīspadsmit').thirteen(), true);
+tap.equal(is('trylika').thirteen(), true);
+tap.equal(is('dräizéng').thirteen(), true);
+tap.equal(is('тринаесет').thirteen(), true);
+tap.equal(is('tiga belas').thirteen(), true);
+tap.equal(is('арван').thirteen(), true);
+tap.equal(is('.---- ...--').thirteen(), true);
+tap.equal(is('matlactlihuan yei').thirteen(), true);
+tap.equal(is('mahtlactli omei').thirteen(), true);
+tap.equal(is('mahtlactli ihuan yei').thirteen(), true);
+tap.equal(is('irteenthay').thirteen(), true);
+tap.equal(is('trzynaście').thirteen(), true);
+tap.equal(is('trzynasty').thirteen(), true);
+tap.equal(is('trzynasta').thirteen(), true);
+tap.equal(is('trzynaste').thirteen(), true);
+tap.equal(is('trzynaści').thirteen(), true);
+tap.equal(is('trzynastego').thirteen(), true);
+tap.equal(is('trzynastej').thirteen(), true);
+tap.equal(is('trzynastych').thirteen(), true);
+tap.equal(is('trzynastemu').thirteen(), true);
+tap.equal(is('trzynastym').thirteen(), true);
+tap.equal(is('trzynastą').thirteen(), true);
+tap.equal(is('trzynastymi').thirteen(), true);
+tap.equal(is('trzynastu').thirteen(), true);
+tap.equal(is('trzynastek').thirteen(), true);
+tap.equal(is('trzynastoma').thirteen(), true);
+tap.equal(is('trzynaścioro').thirteen(), true);
+tap.equal(is('trzynastka').thirteen(), true);
+tap.equal(is('trzynastki').thirteen(), true);
+tap.equal(is('trzynastką').thirteen(), true);
+tap.equal(is('trzynastce').thirteen(), true);
+tap.equal(is('trzynastko').thirteen(), true);
+tap.equal(is('trzynaściorgiem').thirteen(), true);
+tap.equal(is('trzynaściorgu').thirteen(), true);
+tap.equal(is('trzynaściorga').thirteen(), true);
+tap.equal(is('trzynastokrotny').thirteen(), true);
+tap.equal(is('trzynastokrotnie').thirteen(), true);
+tap.equal(is('trzynastokrotną').thirteen(), true);
+tap.equal(is('trzynastokrotnemu').thirteen(), true);
+tap.equal(is('trzynastokrotnej').thirteen(), true);
+tap.equal(is('trzynastokrotnych').thirteen(), true);
+tap.equal(is('trzynastokrotność').thirteen(), true);
+tap.equal(is('trzynastokrotności').thirteen(), true);
+tap.equal(is('trzynastokrotnością').thirteen(), true);
+tap.equal(is('treze').thirteen(), true);
+tap.equal(is('ਤੇਰਾਂ').thirteen(), true);
+tap.equal(is('੧੩').thirteen(), true);
+tap.equal(is('treisprezece').thirteen(), true);
+tap.equal(is('тринадцать').thirteen(), true);
+tap.equal(is('тринаест').thirteen(), true);
+tap.equal(is('trinásť').thirteen(), true);
+tap.equal(is('wa’maH wej').thirteen(), true);
+tap.equal(is('trinajst').thirteen(), true);
+tap.equal(is('trece').thirteen(), true);
+tap.equal(is('dektri').thirteen(), true);
+tap.equal(is('trese').thirteen(), true);
+tap.equal(is('tretton').thirteen(), true);
+tap.equal(is('பதின்மூன்று').thirteen(), true);
+tap.equal(is('สิบสาม').thirteen(), true);
+tap.equal(is('тринадцять').thirteen(), true);
+tap.equal(is('تیرہ').thirteen(), true);
+tap.equal(is('tayra').thirteen(), true);
+tap.equal(is('tri ar ddeg').thirteen(), true);
+tap.equal(is('דרייַצן').thirteen(), true);
+tap.equal(is('דרייצן').thirteen(), true);
+tap.equal(is('kumi na tatu').thirteen(), true);
+tap.equal(is('പതിമൂന്ന്').thirteen(), true);
+tap.equal(is('१३').thirteen(), true);
+tap.equal(is('तेह्र').thirteen(), true);
+tap.equal(is('quainel').thirteen(), true);
+tap.equal(is('mînuiug').thirteen(), true);
+tap.equal(is('7h1r733n').thirteen(), true);
+tap.equal(is('B').thirteen(), true);
+tap.equal(is('b').thirteen(), false);
+tap.equal(is('ß').thirteen(), true);
+tap.equal(is('ẞ').thirteen(), true);
+tap.equal(is('Β').thirteen(), true);
+tap.equal(is('β').thirteen(), true);
+tap.equal(is('阝').thirteen(), true);
+tap.equal(is('i3').thirteen(), true);
+tap.equal(is('I3').thirteen(), true);
+tap.equal(is('l3').thirteen(), true);
+tap.equal(is('L3').thirteen(), true);
+tap.equal(is('|3').thirteen(), true);
+tap.equal(is('!3').thirteen(), true);
+tap.equal(is('Dilma').thirteen(), true);
+tap.equal(is(25 - 12).thirteen(), true);
+tap.equal(is(1 + 12).thirteen(), true);
+tap.equal(is((2 * 8 + 11 - 1) / 2).thirteen(), true);
+tap.equal(is((10 - 1 + 32) / 4 * 3).thirteen(), false);
+tap.equal(is(((5.3 + 0.5) * 5 - 4) / 2).roughly.thirteen(), true);
+tap.equal(is(13).thirteen(), true);
+tap.equal(is(14).thirteen(), false);
+tap.equal(is('1101').base(2).thirteen(), true);
+tap.equal(is('1111').base(2).thirteen(), false);
+tap.equal(is('15').base(8).thirteen(), true);
+tap.equal(is('13').base(8).thirteen(), false);
+tap.equal(is('d').base(16).thirteen(), true);
+tap.equal(is('D').base(16).thirteen(), true);
+tap.equal(is('A').base(16).thirteen(), false);
+tap.equal(is(() => 13).returning.thirteen(), true);
+tap.equal(is('|||||||||||||').thirteen(), true);
+tap.equal(is('/////////////').thirteen(), true);
+tap.equal(is('🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱🐱').thirteen(), true);
+tap.equal(is('oooooooooooooo').thirteen(), false);
+tap.equal(is('bbbbbbbbbbb').thirteen(), false);
+tap.equal(is('||h||||||||||').thirteen(), false);
+tap.equal(is('///i/////////').thirteen(), false);